@@ -0,0 +1,95 @@
+use crate::BigDecimal;
+use std::fmt;
+
+/// The answer to one part of a puzzle.
+///
+/// Most days produce a count or a sum, but keeping the sign and the textual
+/// form distinct lets every day report through the same type. `Unsolved`
+/// marks a part the day has no solver for, or an input it has no answer for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),
+    Text(String),
+    Unsolved,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Unsigned(n) => write!(f, "{}", n),
+            Answer::Signed(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(n: u64) -> Self {
+        Answer::Unsigned(n)
+    }
+}
+
+/// Wide sums that still fit in a `u64` stay numeric; larger ones are kept
+/// exactly as text.
+impl From<u128> for Answer {
+    fn from(n: u128) -> Self {
+        match u64::try_from(n) {
+            Ok(n) => Answer::Unsigned(n),
+            Err(_) => Answer::Text(n.to_string()),
+        }
+    }
+}
+
+/// Like `u128`, numeric while it fits in a `u64` and exact text beyond.
+impl From<BigDecimal> for Answer {
+    fn from(n: BigDecimal) -> Self {
+        match n.to_u128().and_then(|n| u64::try_from(n).ok()) {
+            Some(n) => Answer::Unsigned(n),
+            None => Answer::Text(n.to_string()),
+        }
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        Answer::Unsigned(n as u64)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(n: i64) -> Self {
+        Answer::Signed(n)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(42u64).to_string(), "42");
+        assert_eq!(Answer::from(7usize).to_string(), "7");
+        assert_eq!(Answer::from(42u128), Answer::Unsigned(42));
+        assert_eq!(Answer::from(u128::MAX).to_string(), u128::MAX.to_string());
+        assert_eq!(Answer::from(BigDecimal::from(42u64)), Answer::Unsigned(42));
+        assert_eq!(Answer::from(BigDecimal::pow(10, 40)).to_string(), format!("1{}", "0".repeat(40)));
+        assert_eq!(Answer::from(-3i64).to_string(), "-3");
+        assert_eq!(Answer::from("abc").to_string(), "abc");
+        assert_eq!(Answer::Unsolved.to_string(), "unsolved");
+    }
+}
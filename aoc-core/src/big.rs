@@ -0,0 +1,338 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, Div, Mul, Rem, Sub};
+use std::str::FromStr;
+
+/// Each limb holds nine decimal digits, so printing needs no division.
+const BASE: u32 = 1_000_000_000;
+
+/// An unsigned integer of any size, kept in decimal limbs.
+///
+/// Enough for exact puzzle totals that outgrow `u128`: the four operations,
+/// powers, and reading and writing in any radix from 2 to 36. Subtraction
+/// panics when it would go below zero, like the primitive types do in
+/// debug builds.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigDecimal {
+    /// Least significant first, without zero limbs at the end, so zero has
+    /// none at all.
+    limbs: Vec<u32>,
+}
+
+impl BigDecimal {
+    pub fn zero() -> BigDecimal {
+        BigDecimal::default()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    pub fn from_u128(mut n: u128) -> BigDecimal {
+        let mut limbs = Vec::new();
+        while n > 0 {
+            limbs.push((n % BASE as u128) as u32);
+            n /= BASE as u128;
+        }
+        BigDecimal { limbs }
+    }
+
+    /// The value as a `u128`, if it fits.
+    pub fn to_u128(&self) -> Option<u128> {
+        self.limbs
+            .iter()
+            .rev()
+            .try_fold(0u128, |n, &limb| n.checked_mul(BASE as u128)?.checked_add(limb as u128))
+    }
+
+    /// `base^exp`.
+    pub fn pow(base: u32, exp: u32) -> BigDecimal {
+        (0..exp).fold(BigDecimal::from_u128(1), |power, _| power.mul_small(base))
+    }
+
+    fn trimmed(mut self) -> BigDecimal {
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+        self
+    }
+
+    fn mul_small(&self, factor: u32) -> BigDecimal {
+        let mut carry = 0u64;
+        let mut limbs: Vec<u32> = self
+            .limbs
+            .iter()
+            .map(|&limb| {
+                let product = limb as u64 * factor as u64 + carry;
+                carry = product / BASE as u64;
+                (product % BASE as u64) as u32
+            })
+            .collect();
+        while carry > 0 {
+            limbs.push((carry % BASE as u64) as u32);
+            carry /= BASE as u64;
+        }
+        BigDecimal { limbs }.trimmed()
+    }
+
+    /// The quotient and remainder of dividing by a small `divisor`.
+    pub fn div_rem_small(&self, divisor: u32) -> (BigDecimal, u32) {
+        assert!(divisor > 0, "division by zero");
+        let mut remainder = 0u64;
+        let mut limbs = vec![0; self.limbs.len()];
+        for i in (0..self.limbs.len()).rev() {
+            let current = remainder * BASE as u64 + self.limbs[i] as u64;
+            limbs[i] = (current / divisor as u64) as u32;
+            remainder = current % divisor as u64;
+        }
+        (BigDecimal { limbs }.trimmed(), remainder as u32)
+    }
+
+    /// The quotient and remainder of dividing by `divisor`, one limb of
+    /// quotient at a time, each found by binary search.
+    pub fn div_rem(&self, divisor: &BigDecimal) -> (BigDecimal, BigDecimal) {
+        assert!(!divisor.is_zero(), "division by zero");
+        let mut remainder = BigDecimal::zero();
+        let mut limbs = vec![0; self.limbs.len()];
+        for i in (0..self.limbs.len()).rev() {
+            remainder.limbs.insert(0, self.limbs[i]);
+            remainder = remainder.trimmed();
+            let (mut lo, mut hi) = (0, BASE - 1);
+            while lo < hi {
+                let mid = lo + (hi - lo).div_ceil(2);
+                if divisor.mul_small(mid) <= remainder {
+                    lo = mid;
+                } else {
+                    hi = mid - 1;
+                }
+            }
+            limbs[i] = lo;
+            remainder = remainder - divisor.mul_small(lo);
+        }
+        (BigDecimal { limbs }.trimmed(), remainder)
+    }
+
+    /// Parses digits of `radix` in either case, without sign or prefix.
+    pub fn from_str_radix(digits: &str, radix: u32) -> Option<BigDecimal> {
+        let digits: Vec<u8> = digits.chars().map(|c| c.to_digit(radix).map(|d| d as u8)).collect::<Option<_>>()?;
+        (!digits.is_empty()).then(|| BigDecimal::from_digits(&digits, radix))
+    }
+
+    /// The number with these digits of `radix`, most significant first.
+    /// Decimal digits go straight into limbs, in time linear in their count.
+    pub fn from_digits(digits: &[u8], radix: u32) -> BigDecimal {
+        if radix != 10 {
+            return digits.iter().fold(BigDecimal::zero(), |n, &d| n.mul_small(radix) + BigDecimal::from(d as u64));
+        }
+        let limbs = digits
+            .rchunks(9)
+            .map(|chunk| chunk.iter().fold(0, |limb, &d| limb * 10 + d as u32))
+            .collect();
+        BigDecimal { limbs }.trimmed()
+    }
+
+    /// The digits in `radix`, most significant first.
+    pub fn digits(&self, radix: u32) -> Vec<u8> {
+        let mut digits = Vec::new();
+        let mut rest = self.clone();
+        loop {
+            let (quotient, digit) = rest.div_rem_small(radix);
+            digits.push(digit as u8);
+            rest = quotient;
+            if rest.is_zero() {
+                break;
+            }
+        }
+        digits.reverse();
+        digits
+    }
+
+    /// How many digits the value has in `radix`, counting zero as one.
+    pub fn digit_count(&self, radix: u32) -> u32 {
+        self.digits(radix).len() as u32
+    }
+
+    /// The value written out in `radix`, in lowercase and without a prefix.
+    pub fn format(&self, radix: u32) -> String {
+        self.digits(radix)
+            .into_iter()
+            .map(|d| char::from_digit(d as u32, radix).unwrap())
+            .collect()
+    }
+}
+
+impl Ord for BigDecimal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigDecimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add for BigDecimal {
+    type Output = BigDecimal;
+
+    fn add(self, other: BigDecimal) -> BigDecimal {
+        let mut limbs = Vec::with_capacity(self.limbs.len().max(other.limbs.len()) + 1);
+        let mut carry = 0;
+        for i in 0..self.limbs.len().max(other.limbs.len()) {
+            let sum = self.limbs.get(i).unwrap_or(&0) + other.limbs.get(i).unwrap_or(&0) + carry;
+            limbs.push(sum % BASE);
+            carry = sum / BASE;
+        }
+        if carry > 0 {
+            limbs.push(carry);
+        }
+        BigDecimal { limbs }
+    }
+}
+
+impl Sub for BigDecimal {
+    type Output = BigDecimal;
+
+    fn sub(self, other: BigDecimal) -> BigDecimal {
+        assert!(self >= other, "attempt to subtract with overflow");
+        let mut limbs = Vec::with_capacity(self.limbs.len());
+        let mut borrow = 0;
+        for i in 0..self.limbs.len() {
+            let take = other.limbs.get(i).unwrap_or(&0) + borrow;
+            borrow = (self.limbs[i] < take) as u32;
+            limbs.push(self.limbs[i] + borrow * BASE - take);
+        }
+        BigDecimal { limbs }.trimmed()
+    }
+}
+
+impl Mul for BigDecimal {
+    type Output = BigDecimal;
+
+    fn mul(self, other: BigDecimal) -> BigDecimal {
+        let mut wide = vec![0u64; self.limbs.len() + other.limbs.len()];
+        for (i, &a) in self.limbs.iter().enumerate() {
+            let mut carry = 0;
+            for (j, &b) in other.limbs.iter().enumerate() {
+                let product = wide[i + j] + a as u64 * b as u64 + carry;
+                wide[i + j] = product % BASE as u64;
+                carry = product / BASE as u64;
+            }
+            wide[i + other.limbs.len()] += carry;
+        }
+        BigDecimal {
+            limbs: wide.into_iter().map(|limb| limb as u32).collect(),
+        }
+        .trimmed()
+    }
+}
+
+impl Div for BigDecimal {
+    type Output = BigDecimal;
+
+    fn div(self, other: BigDecimal) -> BigDecimal {
+        self.div_rem(&other).0
+    }
+}
+
+impl Rem for BigDecimal {
+    type Output = BigDecimal;
+
+    fn rem(self, other: BigDecimal) -> BigDecimal {
+        self.div_rem(&other).1
+    }
+}
+
+impl Sum for BigDecimal {
+    fn sum<I: Iterator<Item = BigDecimal>>(iter: I) -> BigDecimal {
+        iter.fold(BigDecimal::zero(), Add::add)
+    }
+}
+
+impl From<u64> for BigDecimal {
+    fn from(n: u64) -> Self {
+        BigDecimal::from_u128(n as u128)
+    }
+}
+
+impl From<u128> for BigDecimal {
+    fn from(n: u128) -> Self {
+        BigDecimal::from_u128(n)
+    }
+}
+
+impl fmt::Display for BigDecimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut text = self.limbs.last().map_or("0".to_string(), u32::to_string);
+        for limb in self.limbs.iter().rev().skip(1) {
+            text.push_str(&format!("{:09}", limb));
+        }
+        f.pad(&text)
+    }
+}
+
+impl FromStr for BigDecimal {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        BigDecimal::from_str_radix(s, 10).ok_or_else(|| format!("invalid number '{}'", s))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn big(s: &str) -> BigDecimal {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = big("123456789012345678901234567890123456789");
+        let b = big("987654321987654321");
+        assert_eq!((a.clone() * b.clone()).to_string(), "121932631246761163237311385323731138532360920590112635269");
+        assert_eq!((a.clone() / b.clone()).to_string(), "124999998748437501153");
+        assert_eq!((a.clone() % b.clone()).to_string(), "142745764920524676");
+        assert_eq!((a.clone() + b.clone() - a.clone()), b);
+        assert_eq!(big("1000000000") - big("1"), big("999999999"));
+        assert_eq!(BigDecimal::pow(10, 20).to_string(), "100000000000000000000");
+        assert_eq!(big("0").to_string(), "0");
+        assert!(big("99") < big("1000000000") && big("1000000001") > big("1000000000"));
+    }
+
+    #[test]
+    fn test_matches_u128() {
+        let values = [0, 1, 999_999_999, 1_000_000_000, u64::MAX as u128, 1 << 100, u128::MAX];
+        for a in values {
+            assert_eq!(BigDecimal::from_u128(a).to_u128(), Some(a));
+            assert_eq!(BigDecimal::from_u128(a).to_string(), a.to_string());
+            for b in values.into_iter().filter(|&b| b > 0) {
+                let (quotient, remainder) = BigDecimal::from_u128(a).div_rem(&BigDecimal::from_u128(b));
+                assert_eq!((quotient.to_u128(), remainder.to_u128()), (Some(a / b), Some(a % b)));
+            }
+        }
+        assert_eq!((BigDecimal::from_u128(u128::MAX) + 1u64.into()).to_u128(), None);
+    }
+
+    #[test]
+    fn test_radix() {
+        let n = BigDecimal::from_str_radix("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF", 16).unwrap();
+        assert_eq!(n.to_u128(), Some(u128::MAX));
+        assert_eq!(n.format(16), "ffffffffffffffffffffffffffffffff");
+        assert_eq!(n.digit_count(2), 128);
+        assert_eq!(BigDecimal::zero().format(8), "0");
+        assert_eq!(BigDecimal::from_str_radix("12a", 10), None);
+        assert_eq!(BigDecimal::from_str_radix("-1", 10), None);
+        assert_eq!(BigDecimal::from_digits(&[0, 0, 1, 2], 10), BigDecimal::from(12u64));
+        assert_eq!(BigDecimal::from_digits(&[0; 20], 10), BigDecimal::zero());
+        let digits = vec![7; 1000];
+        assert_eq!(BigDecimal::from_digits(&digits, 10).to_string(), "7".repeat(1000));
+        assert_eq!(BigDecimal::from_digits(&digits, 10).digits(10), digits);
+    }
+}
@@ -0,0 +1,134 @@
+use std::env;
+use std::fmt;
+use std::str::FromStr;
+
+/// A command-line usage error, shown to the user as-is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArgError(pub String);
+
+impl fmt::Display for ArgError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for ArgError {}
+
+/// A minimal command-line parser for `--flag`, `--name value` and
+/// `--name=value` options mixed with positional arguments.
+///
+/// Options are taken out as they are queried; [`Args::finish`] then rejects
+/// anything that was not recognised and returns the positionals.
+#[derive(Debug, Clone)]
+pub struct Args {
+    args: Vec<String>,
+}
+
+impl Args {
+    pub fn new<I, S>(args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Args {
+            args: args.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// The process arguments, without the program name.
+    pub fn from_env() -> Self {
+        Args::new(env::args().skip(1))
+    }
+
+    /// Removes and returns the first argument if it is not an option.
+    pub fn subcommand(&mut self) -> Option<String> {
+        match self.args.first() {
+            Some(first) if !first.starts_with("--") => Some(self.args.remove(0)),
+            _ => None,
+        }
+    }
+
+    /// Removes `name` and reports whether it was present.
+    pub fn flag(&mut self, name: &str) -> bool {
+        let before = self.args.len();
+        self.args.retain(|arg| arg != name);
+        self.args.len() != before
+    }
+
+    /// Removes `name` and its value, given either as the next argument or
+    /// after an `=`. The last occurrence wins.
+    pub fn value(&mut self, name: &str) -> Result<Option<String>, ArgError> {
+        let prefix = format!("{}=", name);
+        let mut value = None;
+        let mut i = 0;
+        while i < self.args.len() {
+            if self.args[i] == name {
+                if i + 1 >= self.args.len() {
+                    return Err(ArgError(format!("{} needs a value", name)));
+                }
+                value = Some(self.args.remove(i + 1));
+                self.args.remove(i);
+            } else if let Some(rest) = self.args[i].strip_prefix(&prefix) {
+                value = Some(rest.to_string());
+                self.args.remove(i);
+            } else {
+                i += 1;
+            }
+        }
+        Ok(value)
+    }
+
+    /// Like [`Args::value`], parsing the value with `FromStr`.
+    pub fn parsed<T>(&mut self, name: &str) -> Result<Option<T>, ArgError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        match self.value(name)? {
+            Some(value) => value
+                .parse()
+                .map(Some)
+                .map_err(|err| ArgError(format!("invalid value '{}' for {}: {}", value, name, err))),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the remaining positional arguments, failing on any option that
+    /// was never queried.
+    pub fn finish(self) -> Result<Vec<String>, ArgError> {
+        match self.args.iter().find(|arg| arg.starts_with("--")) {
+            Some(unknown) => Err(ArgError(format!("unrecognised option '{}'", unknown))),
+            None => Ok(self.args),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_options_and_positionals() {
+        let mut args = Args::new(["run", "--day", "7", "--part=2", "--example", "extra"]);
+        assert_eq!(args.subcommand().as_deref(), Some("run"));
+        assert_eq!(args.parsed::<u8>("--day"), Ok(Some(7)));
+        assert_eq!(args.value("--part"), Ok(Some("2".to_string())));
+        assert!(args.flag("--example"));
+        assert!(!args.flag("--example"));
+        assert_eq!(args.finish(), Ok(vec!["extra".to_string()]));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(Args::new(["--day"]).value("--day").is_err());
+        assert!(Args::new(["--day", "x"]).parsed::<u8>("--day").is_err());
+        assert!(Args::new(["--bogus"]).finish().is_err());
+        assert_eq!(Args::new(["--day", "7"]).subcommand(), None);
+    }
+
+    #[test]
+    fn test_dash_is_a_value() {
+        let mut args = Args::new(["--input", "-"]);
+        assert_eq!(args.value("--input"), Ok(Some("-".to_string())));
+    }
+}
@@ -0,0 +1,82 @@
+use crate::rng::Rng;
+use crate::Solution;
+
+/// A size knob of an input generator, such as how many lines to produce.
+#[derive(Debug, Clone, Copy)]
+pub struct Knob {
+    pub name: &'static str,
+    pub default: u64,
+    pub help: &'static str,
+}
+
+/// The knob values one generator run uses: the defaults with any overrides.
+#[derive(Debug, Clone)]
+pub struct Knobs {
+    values: Vec<(&'static str, u64)>,
+}
+
+impl Knobs {
+    pub fn new(knobs: &[Knob]) -> Self {
+        Knobs {
+            values: knobs.iter().map(|knob| (knob.name, knob.default)).collect(),
+        }
+    }
+
+    /// Overrides one knob, returning false if there is no knob by that name.
+    pub fn set(&mut self, name: &str, value: u64) -> bool {
+        match self.values.iter_mut().find(|(n, _)| *n == name) {
+            Some((_, v)) => {
+                *v = value;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn get(&self, name: &str) -> u64 {
+        self.values
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map(|&(_, v)| v)
+            .unwrap_or_else(|| panic!("generator has no knob named '{}'", name))
+    }
+
+    /// Like [`Knobs::get`], for knobs that count or size things.
+    pub fn size(&self, name: &str) -> usize {
+        self.get(name) as usize
+    }
+}
+
+/// A day that can make up random but valid puzzle inputs, for stress tests
+/// and scaling measurements.
+pub trait Generator: Solution {
+    /// The knobs `generate` reads, with their defaults.
+    const KNOBS: &'static [Knob];
+
+    fn generate(rng: &mut Rng, knobs: &Knobs) -> String;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KNOBS: &[Knob] = &[
+        Knob { name: "lines", default: 10, help: "number of lines" },
+        Knob { name: "width", default: 3, help: "characters per line" },
+    ];
+
+    #[test]
+    fn test_knobs_start_at_defaults_and_can_be_overridden() {
+        let mut knobs = Knobs::new(KNOBS);
+        assert_eq!(knobs.get("lines"), 10);
+        assert!(knobs.set("width", 80));
+        assert!(!knobs.set("height", 1));
+        assert_eq!(knobs.size("width"), 80);
+    }
+
+    #[test]
+    #[should_panic(expected = "no knob named 'depth'")]
+    fn test_unknown_knob_panics() {
+        Knobs::new(KNOBS).get("depth");
+    }
+}
@@ -0,0 +1,332 @@
+use crate::parse::ParseError;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// A cell position as `(row, col)`.
+pub type Pos = (usize, usize);
+
+/// Row/column offsets of the four orthogonal neighbours.
+pub const NEIGHBOURS_4: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+/// Row/column offsets of all eight neighbours, diagonals included.
+#[rustfmt::skip]
+pub const NEIGHBOURS_8: [(isize, isize); 8] = [
+    (-1, -1), (-1, 0), (-1, 1),
+    (0, -1),           (0, 1),
+    (1, -1),  (1, 0),  (1, 1),
+];
+
+/// Moves `(row, col)` by `delta`, or returns `None` if that leaves a grid of
+/// `rows` by `cols` cells.
+pub fn step(
+    (row, col): Pos,
+    (dr, dc): (isize, isize),
+    rows: usize,
+    cols: usize,
+) -> Option<Pos> {
+    let row = row.checked_add_signed(dr)?;
+    let col = col.checked_add_signed(dc)?;
+    (row < rows && col < cols).then_some((row, col))
+}
+
+/// A rectangular grid stored row by row in one flat vector.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    rows: usize,
+    cols: usize,
+}
+
+impl<T> Grid<T> {
+    /// A `rows` by `cols` grid with every cell set to `fill`.
+    pub fn new(rows: usize, cols: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: vec![fill; rows * cols],
+            rows,
+            cols,
+        }
+    }
+
+    /// Parses one row per line of `input`, turning each character into a cell
+    /// with `cell`. Characters it rejects and rows of the wrong width are
+    /// reported, the former as not being `expected`.
+    pub fn parse(
+        input: &str,
+        cell: impl FnMut(char) -> Option<T>,
+        expected: &str,
+    ) -> Result<Self, ParseError>
+    where
+        T: Clone,
+    {
+        Grid::parse_rows(input, None, cell, expected)
+    }
+
+    /// Like [`Grid::parse`], but pads short rows with `pad` to the width of
+    /// the longest one instead of rejecting them.
+    pub fn parse_padded(
+        input: &str,
+        pad: T,
+        cell: impl FnMut(char) -> Option<T>,
+        expected: &str,
+    ) -> Result<Self, ParseError>
+    where
+        T: Clone,
+    {
+        Grid::parse_rows(input, Some(pad), cell, expected)
+    }
+
+    fn parse_rows(
+        input: &str,
+        pad: Option<T>,
+        mut cell: impl FnMut(char) -> Option<T>,
+        expected: &str,
+    ) -> Result<Self, ParseError>
+    where
+        T: Clone,
+    {
+        let lines: Vec<&str> = input
+            .trim_end_matches(['\n', '\r'])
+            .lines()
+            .map(|line| line.trim_end_matches('\r'))
+            .collect();
+        let widths = lines.iter().map(|line| line.chars().count());
+        let cols = match pad {
+            Some(_) => widths.max().unwrap_or(0),
+            None => lines.first().map_or(0, |line| line.chars().count()),
+        };
+
+        let mut cells = Vec::with_capacity(lines.len() * cols);
+        for line in &lines {
+            let mut width = 0;
+            for (i, c) in line.char_indices() {
+                if width == cols {
+                    let expected = format!("a row of {} cells", cols);
+                    return Err(ParseError::at(input, &line[i..], expected));
+                }
+                match cell(c) {
+                    Some(value) => cells.push(value),
+                    None => return Err(ParseError::at(input, &line[i..i + c.len_utf8()], expected)),
+                }
+                width += 1;
+            }
+            match &pad {
+                Some(pad) => cells.extend(std::iter::repeat_n(pad.clone(), cols - width)),
+                None if width < cols => {
+                    let expected = format!("a row of {} cells", cols);
+                    return Err(ParseError::at(input, &line[line.len()..], expected));
+                }
+                None => {}
+            }
+        }
+
+        Ok(Grid {
+            cells,
+            rows: lines.len(),
+            cols,
+        })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn get(&self, (row, col): Pos) -> Option<&T> {
+        (row < self.rows && col < self.cols).then(|| &self.cells[row * self.cols + col])
+    }
+
+    pub fn get_mut(&mut self, (row, col): Pos) -> Option<&mut T> {
+        if row < self.rows && col < self.cols {
+            Some(&mut self.cells[row * self.cols + col])
+        } else {
+            None
+        }
+    }
+
+    /// The cells of one row, left to right.
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    /// The cells of one column, top to bottom.
+    pub fn column(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(col < self.cols, "column {} out of bounds", col);
+        self.cells.iter().skip(col).step_by(self.cols).take(self.rows)
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let cols = self.cols;
+        (0..self.rows * cols).map(move |i| (i / cols, i % cols))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// Moves `pos` by `delta`, or returns `None` if that leaves the grid.
+    pub fn step(&self, pos: Pos, delta: (isize, isize)) -> Option<Pos> {
+        step(pos, delta, self.rows, self.cols)
+    }
+
+    /// The positions reached from `pos` by each of `offsets` that stay
+    /// inside the grid.
+    pub fn neighbours<'a>(
+        &self,
+        pos: Pos,
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = Pos> + 'a {
+        let (rows, cols) = (self.rows, self.cols);
+        offsets
+            .iter()
+            .filter_map(move |&delta| step(pos, delta, rows, cols))
+    }
+
+    /// The orthogonal neighbours of `pos` inside the grid.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> {
+        self.neighbours(pos, &NEIGHBOURS_4)
+    }
+
+    /// All eight neighbours of `pos` inside the grid.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> {
+        self.neighbours(pos, &NEIGHBOURS_8)
+    }
+
+    /// Finds the first cell holding `target`, scanning row by row.
+    pub fn find(&self, target: &T) -> Option<Pos>
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .find_map(|(pos, cell)| (cell == target).then_some(pos))
+    }
+
+    /// A grid of the same shape with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+
+    /// Draws the grid one line per row, showing each cell as `cell` returns.
+    pub fn render(&self, mut cell: impl FnMut(&T) -> char) -> String {
+        let mut out = String::with_capacity(self.rows * (self.cols + 1));
+        for row in 0..self.rows {
+            out.extend(self.row(row).iter().map(&mut cell));
+            out.push('\n');
+        }
+        out
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): Pos) -> &T {
+        assert!(row < self.rows && col < self.cols, "({}, {}) out of bounds", row, col);
+        &self.cells[row * self.cols + col]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, (row, col): Pos) -> &mut T {
+        assert!(row < self.rows && col < self.cols, "({}, {}) out of bounds", row, col);
+        &mut self.cells[row * self.cols + col]
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in 0..self.rows {
+            for cell in self.row(row) {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chars(input: &str) -> Result<Grid<char>, ParseError> {
+        Grid::parse(input, |c| ".@S".contains(c).then_some(c), "one of `.@S`")
+    }
+
+    #[test]
+    fn test_step_stays_in_bounds() {
+        assert_eq!(step((0, 0), (-1, 0), 3, 3), None);
+        assert_eq!(step((0, 0), (1, 1), 3, 3), Some((1, 1)));
+        assert_eq!(step((2, 1), (0, 1), 3, 3), Some((2, 2)));
+        assert_eq!(step((2, 2), (0, 1), 3, 3), None);
+    }
+
+    #[test]
+    fn test_parse_and_find() {
+        let grid = chars("..\r\n.S\r\n").unwrap();
+        assert_eq!((grid.rows(), grid.cols()), (2, 2));
+        assert_eq!(grid[(1, 1)], 'S');
+        assert_eq!(grid.find(&'S'), Some((1, 1)));
+        assert_eq!(grid.find(&'@'), None);
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(chars("").unwrap().rows(), 0);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = chars(".@\n.x").unwrap_err();
+        assert_eq!((err.line, err.column, err.snippet.as_str()), (2, 2, "x"));
+        assert_eq!(err.expected, "one of `.@S`");
+
+        let err = chars("..\n...\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.snippet.as_str()), (2, 3, "."));
+        let err = chars("..\n.\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.snippet.as_str()), (2, 2, ""));
+    }
+
+    #[test]
+    fn test_parse_padded() {
+        let grid = Grid::parse_padded("12\n3\n", ' ', Some, "").unwrap();
+        assert_eq!(grid.to_string(), "12\n3 \n");
+    }
+
+    #[test]
+    fn test_views() {
+        let grid = Grid::parse("abc\ndef", Some, "").unwrap();
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.column(2).collect::<String>(), "cf");
+        assert_eq!(grid.column(0).rev().collect::<String>(), "da");
+        assert_eq!(grid.positions().last(), Some((1, 2)));
+        assert_eq!(grid.map(|c| c.is_ascii_uppercase()).render(|&b| if b { '#' } else { '.' }), "...\n...\n");
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 3, 0u8);
+        assert_eq!(grid.neighbours4((0, 0)).collect::<Vec<_>>(), vec![(0, 1), (1, 0)]);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+        assert_eq!(grid.neighbours8((2, 2)).count(), 3);
+        let knight = [(1, 2), (2, 1), (-1, 2)];
+        assert_eq!(grid.neighbours((0, 0), &knight).collect::<Vec<_>>(), vec![(1, 2), (2, 1)]);
+    }
+
+    #[test]
+    fn test_index_mut() {
+        let mut grid = Grid::new(2, 3, false);
+        grid[(1, 2)] = true;
+        *grid.get_mut((0, 0)).unwrap() = true;
+        assert_eq!(grid.render(|&b| if b { '#' } else { '.' }), "#..\n..#\n");
+        assert_eq!(grid.iter().filter(|(_, &b)| b).count(), 2);
+    }
+}
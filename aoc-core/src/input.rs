@@ -0,0 +1,117 @@
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::process;
+
+use crate::Solution;
+
+/// File name of the personal puzzle input kept in each day's directory.
+pub const INPUT_FILE: &str = "input.txt";
+
+/// File name of the worked example from the puzzle text, where a day has one.
+pub const EXAMPLE_FILE: &str = "test_input.txt";
+
+/// Reads an input file, naming the path in the error.
+pub fn read_input(path: impl AsRef<Path>) -> io::Result<String> {
+    let path = path.as_ref();
+    fs::read_to_string(path).map_err(|err| {
+        io::Error::new(err.kind(), format!("failed to read {}: {}", path.display(), err))
+    })
+}
+
+/// Reads all of standard input.
+pub fn read_stdin() -> io::Result<String> {
+    let mut input = String::new();
+    io::stdin().read_to_string(&mut input)?;
+    Ok(input)
+}
+
+/// FNV-1a hash of an input's bytes, used to tell inputs apart in reports and
+/// recorded answers without storing the input itself.
+pub fn hash(input: &str) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    input.bytes().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(PRIME)
+    })
+}
+
+/// Path of the day's `input.txt`.
+///
+/// `day_dir` is the day crate's `CARGO_MANIFEST_DIR`, so the binaries find
+/// their input whether they are run from the workspace root or the day itself.
+pub fn default_path(day_dir: &str) -> PathBuf {
+    Path::new(day_dir).join(INPUT_FILE)
+}
+
+/// Puzzle input read from a file, remembered alongside its path so parse
+/// errors can point back at it.
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub path: PathBuf,
+    pub text: String,
+}
+
+impl Input {
+    /// Parses the input for `S`. A malformed input is reported as a
+    /// diagnostic on stderr and ends the process, which is what a day's
+    /// binary wants.
+    pub fn parse<S: Solution>(&self) -> S::Parsed {
+        S::parse(&self.text).unwrap_or_else(|err| {
+            eprint!("{}", err.render(&self.text, &self.path.display().to_string()));
+            process::exit(1)
+        })
+    }
+}
+
+/// Reads the day's `input.txt`, panicking with the path if that fails.
+pub fn load(day_dir: &str) -> Input {
+    load_path(default_path(day_dir))
+}
+
+/// Reads the file named by the first command-line argument, falling back to
+/// the day's `input.txt` when no argument is given.
+pub fn load_from_args(day_dir: &str) -> Input {
+    load_from(day_dir, env::args().nth(1))
+}
+
+/// Reads `path`, or the day's `input.txt` when there is none.
+pub fn load_from(day_dir: &str, path: Option<String>) -> Input {
+    match path {
+        Some(path) => load_path(PathBuf::from(path)),
+        None => load(day_dir),
+    }
+}
+
+fn load_path(path: PathBuf) -> Input {
+    let text = read_input(&path).unwrap_or_else(|err| panic!("{}", err));
+    Input { path, text }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_path() {
+        let path = default_path("/somewhere/day-3");
+        assert_eq!(path, Path::new("/somewhere/day-3/input.txt"));
+    }
+
+    #[test]
+    fn test_hash() {
+        // Reference values for 64-bit FNV-1a.
+        assert_eq!(hash(""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(hash("a"), 0xaf63_dc4c_8601_ec8c);
+        assert_ne!(hash("1-2"), hash("1-3"));
+    }
+
+    #[test]
+    fn test_read_input_names_missing_file() {
+        let err = read_input("/definitely/not/here.txt").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+        assert!(err.to_string().contains("/definitely/not/here.txt"));
+    }
+}
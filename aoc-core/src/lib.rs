@@ -0,0 +1,23 @@
+//! Shared building blocks for the Advent of Code 2025 solutions.
+//!
+//! Every day crate depends on this library for reading its input, parsing
+//! common shapes of text, working with grids and reporting answers
+//! through the [`Solution`] trait. Days that implement [`gen::Generator`]
+//! can also make up random inputs of any size from a seed, and [`oracle`]
+//! checks fast solvers against slow reference ones on random cases.
+
+pub mod answer;
+pub mod big;
+pub mod cli;
+pub mod gen;
+pub mod grid;
+pub mod input;
+pub mod oracle;
+pub mod parse;
+pub mod rng;
+pub mod solution;
+pub mod timing;
+
+pub use answer::Answer;
+pub use big::BigDecimal;
+pub use solution::{Detail, Part, Solution};
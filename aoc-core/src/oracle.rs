@@ -0,0 +1,205 @@
+use crate::rng::Rng;
+use std::any::Any;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+
+/// Most shrinking steps taken before settling for the case reached so far.
+const MAX_SHRINKS: usize = 10_000;
+
+/// A fast solver paired with a slow, obviously correct reference for the
+/// same question, plus a way to make up small cases and shrink them.
+pub struct Oracle<T, R> {
+    pub name: &'static str,
+    /// A small random case.
+    pub generate: fn(&mut Rng) -> T,
+    /// Slightly simpler variants of a case, tried in order while shrinking.
+    pub shrink: fn(&T) -> Vec<T>,
+    /// The case as a human would want to read it, ideally as puzzle input.
+    pub render: fn(&T) -> String,
+    pub fast: fn(&T) -> R,
+    pub reference: fn(&T) -> R,
+}
+
+/// A shrunk case the fast and reference solvers disagree on. A solver that
+/// panics disagrees with everything.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    /// Seed of the failing case; checking one case from it reproduces it.
+    pub seed: u64,
+    pub shrinks: usize,
+    pub input: String,
+    pub fast: String,
+    pub reference: String,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "fast and reference disagree on case seed {} (shrunk {} times)", self.seed, self.shrinks)?;
+        writeln!(f, "input:")?;
+        for line in self.input.lines() {
+            writeln!(f, "    {}", line)?;
+        }
+        writeln!(f, "fast:      {}", self.fast)?;
+        write!(f, "reference: {}", self.reference)
+    }
+}
+
+/// A differential check, with the case and answer types erased so checks
+/// of different days can sit in one list.
+pub trait Check {
+    fn name(&self) -> &'static str;
+
+    /// Compares the solvers on `cases` cases seeded `seed`, `seed + 1`, and
+    /// so on, stopping at the first disagreement.
+    fn run(&self, seed: u64, cases: u64) -> Result<(), Mismatch>;
+}
+
+impl<T, R: PartialEq + fmt::Debug> Oracle<T, R> {
+    fn outcomes(&self, case: &T) -> (Result<R, String>, Result<R, String>) {
+        (catch(|| (self.fast)(case)), catch(|| (self.reference)(case)))
+    }
+
+    fn fails(&self, case: &T) -> bool {
+        !matches!(self.outcomes(case), (Ok(fast), Ok(reference)) if fast == reference)
+    }
+
+    /// Greedily takes the first shrink candidate that still fails until none
+    /// does.
+    fn minimise(&self, mut case: T) -> (T, usize) {
+        let mut shrinks = 0;
+        'shrink: while shrinks < MAX_SHRINKS {
+            for candidate in (self.shrink)(&case) {
+                if self.fails(&candidate) {
+                    case = candidate;
+                    shrinks += 1;
+                    continue 'shrink;
+                }
+            }
+            break;
+        }
+        (case, shrinks)
+    }
+}
+
+impl<T, R: PartialEq + fmt::Debug> Check for Oracle<T, R> {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn run(&self, seed: u64, cases: u64) -> Result<(), Mismatch> {
+        for seed in (0..cases).map(|i| seed.wrapping_add(i)) {
+            let case = (self.generate)(&mut Rng::new(seed));
+            if !self.fails(&case) {
+                continue;
+            }
+            let (case, shrinks) = self.minimise(case);
+            let (fast, reference) = self.outcomes(&case);
+            return Err(Mismatch {
+                seed,
+                shrinks,
+                input: (self.render)(&case),
+                fast: describe(fast),
+                reference: describe(reference),
+            });
+        }
+        Ok(())
+    }
+}
+
+fn catch<R>(solve: impl FnOnce() -> R) -> Result<R, String> {
+    panic::catch_unwind(AssertUnwindSafe(solve)).map_err(|payload| panic_message(&*payload))
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "a non-string panic".to_string()
+    }
+}
+
+fn describe<R: fmt::Debug>(outcome: Result<R, String>) -> String {
+    match outcome {
+        Ok(answer) => format!("{:?}", answer),
+        Err(message) => format!("panicked: {}", message),
+    }
+}
+
+/// Shrink candidates for an inclusive range: narrower ranges, halves first.
+pub fn shrink_range((start, end): (u64, u64)) -> Vec<(u64, u64)> {
+    let mut candidates = Vec::new();
+    if start < end {
+        let mid = start + (end - start) / 2;
+        candidates.extend([(start, mid), (mid + 1, end), (start + 1, end), (start, end - 1)]);
+    }
+    candidates
+}
+
+/// Shrink candidates for a list: without each item, then with each item
+/// replaced by each of its own shrink candidates.
+pub fn shrink_vec<T: Clone>(items: &[T], item: impl Fn(&T) -> Vec<T>) -> Vec<Vec<T>> {
+    let mut candidates = Vec::new();
+    for i in 0..items.len() {
+        let mut fewer = items.to_vec();
+        fewer.remove(i);
+        candidates.push(fewer);
+    }
+    for (i, value) in items.iter().enumerate() {
+        for smaller in item(value) {
+            let mut changed = items.to_vec();
+            changed[i] = smaller;
+            candidates.push(changed);
+        }
+    }
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Claims the sum of a list is below 100 whenever it has under 5 items.
+    fn sloppy_sum() -> Oracle<Vec<u64>, u64> {
+        Oracle {
+            name: "sum",
+            generate: |rng| (0..rng.range(0..=8)).map(|_| rng.range(0..=60)).collect(),
+            shrink: |items| shrink_vec(items, |&n| if n > 0 { vec![n / 2, n - 1] } else { vec![] }),
+            render: |items| format!("{:?}", items),
+            fast: |items| if items.len() < 5 { items.iter().sum::<u64>().min(99) } else { items.iter().sum() },
+            reference: |items| items.iter().sum(),
+        }
+    }
+
+    #[test]
+    fn test_agreeing_solvers_pass() {
+        let mut oracle = sloppy_sum();
+        oracle.fast = |items| items.iter().sum();
+        assert_eq!(oracle.run(0, 500), Ok(()));
+    }
+
+    #[test]
+    fn test_counterexamples_are_shrunk_and_reproducible() {
+        let oracle = sloppy_sum();
+        let mismatch = oracle.run(0, 500).unwrap_err();
+        // Shrinking stops once every item matters and the sum is exactly 100.
+        assert_eq!((mismatch.fast.as_str(), mismatch.reference.as_str()), ("99", "100"));
+        assert_eq!(oracle.run(mismatch.seed, 1), Err(mismatch));
+    }
+
+    #[test]
+    fn test_panics_count_as_disagreement() {
+        let mut oracle = sloppy_sum();
+        oracle.fast = |items| if items.len() > 3 { panic!("too long") } else { items.iter().sum() };
+        let mismatch = oracle.run(0, 500).unwrap_err();
+        assert_eq!(mismatch.fast, "panicked: too long");
+        assert_eq!(mismatch.input, "[0, 0, 0, 0]");
+    }
+
+    #[test]
+    fn test_shrink_range() {
+        assert_eq!(shrink_range((5, 5)), vec![]);
+        assert_eq!(shrink_range((10, 20)), vec![(10, 15), (16, 20), (11, 20), (10, 19)]);
+    }
+}
@@ -0,0 +1,214 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// Iterates over the non-blank lines of `input`, with trailing whitespace
+/// (including the `\r` of Windows line endings) removed.
+pub fn lines(input: &str) -> impl Iterator<Item = &str> {
+    input
+        .lines()
+        .map(str::trim_end)
+        .filter(|line| !line.is_empty())
+}
+
+/// Splits `input` into blocks separated by one or more blank lines.
+///
+/// Unlike `split("\n\n")` this tolerates `\r\n` line endings and runs of
+/// blank lines, and never yields an empty section.
+pub fn sections(input: &str) -> Vec<&str> {
+    let mut sections = Vec::new();
+    let mut start = None;
+    let mut end = 0;
+    let mut offset = 0;
+
+    for line in input.split_inclusive('\n') {
+        if line.trim().is_empty() {
+            if let Some(s) = start.take() {
+                sections.push(&input[s..end]);
+            }
+        } else {
+            start.get_or_insert(offset);
+            end = offset + line.trim_end().len();
+        }
+        offset += line.len();
+    }
+    if let Some(s) = start {
+        sections.push(&input[s..end]);
+    }
+
+    sections
+}
+
+/// Parses `field`, a slice of `source`, after trimming surrounding
+/// whitespace. On failure the error points at the field and says `expected`.
+pub fn number<T: FromStr>(source: &str, field: &str, expected: &str) -> Result<T, ParseError> {
+    let trimmed = field.trim();
+    let trimmed = if trimmed.is_empty() { field } else { trimmed };
+    trimmed
+        .parse()
+        .map_err(|_| ParseError::at(source, trimmed, expected))
+}
+
+/// Parses every non-empty, `sep`-separated field of `s`, a slice of
+/// `source`, trimming whitespace around each one.
+pub fn numbers<T: FromStr>(source: &str, s: &str, sep: char) -> Result<Vec<T>, ParseError> {
+    s.split(sep)
+        .filter(|field| !field.trim().is_empty())
+        .map(|field| number(source, field, "an integer"))
+        .collect()
+}
+
+/// Byte offset of `fragment` within `source`.
+///
+/// `fragment` must be a subslice of `source`, as produced by `lines`, `split`
+/// or `trim`; anything else is a bug in the caller.
+pub fn offset_of(source: &str, fragment: &str) -> usize {
+    let start = source.as_ptr() as usize;
+    let at = fragment.as_ptr() as usize;
+    assert!(
+        at >= start && at + fragment.len() <= start + source.len(),
+        "fragment is not part of the source text"
+    );
+    at - start
+}
+
+/// A problem found while parsing puzzle input, located precisely enough to
+/// point at it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    /// The offending text; empty when something is missing.
+    pub snippet: String,
+    /// What the parser was looking for.
+    pub expected: String,
+}
+
+impl ParseError {
+    /// An error about `fragment`, which must be a slice of `source`.
+    pub fn at(source: &str, fragment: &str, expected: impl Into<String>) -> Self {
+        ParseError::at_offset(source, offset_of(source, fragment), fragment.len(), expected)
+    }
+
+    /// An error about the `len` bytes of `source` starting at byte `offset`.
+    pub fn at_offset(source: &str, offset: usize, len: usize, expected: impl Into<String>) -> Self {
+        let before = &source[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let snippet = &source[offset..offset + len];
+        // Never let the snippet run past the end of its line.
+        let snippet = snippet.split(['\r', '\n']).next().unwrap_or("");
+        ParseError {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            snippet: snippet.to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Renders the error the way a compiler would, quoting the offending line
+    /// of `source` and underlining the snippet. `origin` names the input,
+    /// usually its path.
+    pub fn render(&self, source: &str, origin: &str) -> String {
+        let text = source
+            .lines()
+            .nth(self.line - 1)
+            .unwrap_or("")
+            .trim_end_matches('\r');
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+        let underline = "^".repeat(self.snippet.chars().count().max(1));
+        format!(
+            "error: {}\n{}--> {}:{}:{}\n{} |\n{} | {}\n{} | {}{} expected {}\n",
+            self.message(),
+            gutter,
+            origin,
+            self.line,
+            self.column,
+            gutter,
+            number,
+            text,
+            gutter,
+            " ".repeat(self.column - 1),
+            underline,
+            self.expected
+        )
+    }
+
+    fn message(&self) -> String {
+        if self.snippet.is_empty() {
+            format!("expected {}, found nothing", self.expected)
+        } else {
+            format!("expected {}, found `{}`", self.expected, self.snippet)
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: {}", self.line, self.column, self.message())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lines_skips_blanks_and_carriage_returns() {
+        let input = "a\r\n\r\nb  \nc";
+        assert_eq!(lines(input).collect::<Vec<_>>(), vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_sections() {
+        assert_eq!(sections("1-2\n3-4\n\n5\n6\n"), vec!["1-2\n3-4", "5\n6"]);
+        assert_eq!(sections("a\r\nb\r\n\r\n\r\nc\r\n"), vec!["a\r\nb", "c"]);
+        assert!(sections("\n\n").is_empty());
+    }
+
+    #[test]
+    fn test_numbers() {
+        assert_eq!(numbers::<i64>("7, -1,3", "7, -1,3", ','), Ok(vec![7, -1, 3]));
+        assert_eq!(numbers::<usize>("0,2,", "0,2,", ','), Ok(vec![0, 2]));
+
+        let source = "1,2\n3, x4,5";
+        let line = source.lines().nth(1).unwrap();
+        let err = numbers::<u32>(source, line, ',').unwrap_err();
+        assert_eq!((err.line, err.column), (2, 4));
+        assert_eq!(err.snippet, "x4");
+        assert_eq!(err.expected, "an integer");
+    }
+
+    #[test]
+    fn test_error_location_counts_characters() {
+        let source = "ab\nµx: 12\n";
+        let err = ParseError::at(source, &source[6..8], "a digit");
+        assert_eq!((err.line, err.column, err.snippet.as_str()), (2, 3, ": "));
+        assert_eq!(err.to_string(), "line 2, column 3: expected a digit, found `: `");
+    }
+
+    #[test]
+    fn test_missing_text_is_reported_at_its_position() {
+        let source = "10-";
+        let err = number::<u64>(source, &source[3..], "a range end").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 4));
+        assert_eq!(err.to_string(), "line 1, column 4: expected a range end, found nothing");
+    }
+
+    #[test]
+    fn test_render() {
+        let source = "7,1\n12,ab\n";
+        let err = ParseError::at(source, &source[7..9], "an integer");
+        let expected = "\
+error: expected an integer, found `ab`
+ --> day-9/input.txt:2:4
+  |
+2 | 12,ab
+  |    ^^ expected an integer
+";
+        assert_eq!(err.render(source, "day-9/input.txt"), expected);
+    }
+}
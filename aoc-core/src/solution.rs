@@ -0,0 +1,156 @@
+use crate::parse::ParseError;
+use crate::Answer;
+use std::fmt;
+use std::str::FromStr;
+
+/// One of the two parts of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("invalid part '{}', expected 1 or 2", s)),
+        }
+    }
+}
+
+/// A supporting value a day reports next to an answer, such as an
+/// intermediate count that helps explain it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Detail {
+    Int(i64),
+    List(Vec<i64>),
+    Text(String),
+}
+
+impl fmt::Display for Detail {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Detail::Int(n) => write!(f, "{}", n),
+            Detail::List(values) => write!(f, "{:?}", values),
+            Detail::Text(s) => f.write_str(s),
+        }
+    }
+}
+
+/// The common shape of every day's solver.
+///
+/// Input is parsed once into `Parsed` and both parts are answered from it, so
+/// tooling can drive any day without knowing how it works inside.
+pub trait Solution {
+    /// Day of the month the puzzle was released.
+    const DAY: u8;
+
+    /// The puzzle input after parsing, shared by both parts.
+    type Parsed;
+
+    /// Parses the puzzle input, pointing at the first thing that does not
+    /// fit the expected format.
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError>;
+
+    fn part1(parsed: &Self::Parsed) -> Answer;
+
+    fn part2(parsed: &Self::Parsed) -> Answer;
+
+    /// Named values worth reporting alongside the answer to `part`.
+    fn details(_parsed: &Self::Parsed, _part: Part) -> Vec<(&'static str, Detail)> {
+        Vec::new()
+    }
+}
+
+/// Parses `input` and answers both parts of `S`.
+pub fn solve<S: Solution>(input: &str) -> Result<(Answer, Answer), ParseError> {
+    let parsed = S::parse(input)?;
+    Ok((S::part1(&parsed), S::part2(&parsed)))
+}
+
+/// Answers a single `part` of `S` from already parsed input.
+pub fn solve_part<S: Solution>(parsed: &S::Parsed, part: Part) -> Answer {
+    match part {
+        Part::One => S::part1(parsed),
+        Part::Two => S::part2(parsed),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct LineCount;
+
+    impl Solution for LineCount {
+        const DAY: u8 = 0;
+        type Parsed = Vec<String>;
+
+        fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+            input
+                .lines()
+                .map(|line| match line.find(char::is_whitespace) {
+                    Some(i) => Err(ParseError::at(input, &line[i..i + 1], "a word")),
+                    None => Ok(line.to_string()),
+                })
+                .collect()
+        }
+
+        fn part1(parsed: &Self::Parsed) -> Answer {
+            parsed.len().into()
+        }
+
+        fn part2(_: &Self::Parsed) -> Answer {
+            Answer::Unsolved
+        }
+    }
+
+    #[test]
+    fn test_solve_parses_once_for_both_parts() {
+        let (part1, part2) = solve::<LineCount>("a\nb\nc").unwrap();
+        assert_eq!(part1, Answer::Unsigned(3));
+        assert_eq!(part2, Answer::Unsolved);
+    }
+
+    #[test]
+    fn test_solve_reports_parse_errors() {
+        let err = solve::<LineCount>("a\nb c").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+    }
+
+    #[test]
+    fn test_details_default_to_empty() {
+        let parsed = LineCount::parse("a").unwrap();
+        assert!(LineCount::details(&parsed, Part::One).is_empty());
+        assert_eq!(Detail::List(vec![3, 1]).to_string(), "[3, 1]");
+    }
+
+    #[test]
+    fn test_part_round_trips() {
+        for part in Part::BOTH {
+            assert_eq!(part.to_string().parse::<Part>(), Ok(part));
+        }
+        assert!("3".parse::<Part>().is_err());
+    }
+}
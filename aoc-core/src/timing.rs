@@ -0,0 +1,43 @@
+use std::time::{Duration, Instant};
+
+/// Runs `f` and returns its result together with the wall-clock time taken.
+pub fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+/// Formats a duration with a unit suited to its size, e.g. `812ns`,
+/// `15.2µs`, `3.41ms` or `1.20s`.
+pub fn format_duration(d: Duration) -> String {
+    let nanos = d.as_nanos();
+    if nanos < 1_000 {
+        format!("{}ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.1}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.2}ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2}s", d.as_secs_f64())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timed_returns_result() {
+        let (value, elapsed) = timed(|| 6 * 7);
+        assert_eq!(value, 42);
+        assert!(elapsed < Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(812)), "812ns");
+        assert_eq!(format_duration(Duration::from_nanos(15_200)), "15.2µs");
+        assert_eq!(format_duration(Duration::from_micros(3_410)), "3.41ms");
+        assert_eq!(format_duration(Duration::from_millis(1_200)), "1.20s");
+    }
+}
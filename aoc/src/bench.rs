@@ -0,0 +1,318 @@
+use crate::alloc::Allocations;
+use crate::days::Day;
+use aoc_core::parse::ParseError;
+use aoc_core::timing::format_duration;
+use aoc_core::Part;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// File name of the saved benchmark baseline, kept at the workspace root.
+pub const BASELINE_FILE: &str = "bench-baseline.txt";
+
+/// A separately timed stage of solving a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Phase {
+    pub const ALL: [Phase; 3] = [Phase::Parse, Phase::Part1, Phase::Part2];
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Phase::Parse => "parse",
+            Phase::Part1 => "part1",
+            Phase::Part2 => "part2",
+        })
+    }
+}
+
+impl FromStr for Phase {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "parse" => Ok(Phase::Parse),
+            "part1" => Ok(Phase::Part1),
+            "part2" => Ok(Phase::Part2),
+            _ => Err(format!("unknown phase '{}'", s)),
+        }
+    }
+}
+
+/// Summary of the timings of one phase over many iterations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    /// What a single iteration allocated.
+    pub allocations: Allocations,
+}
+
+impl Stats {
+    /// Summarises `samples`, which must not be empty. Percentiles use the
+    /// nearest-rank method, so every reported time was actually observed.
+    pub fn from_samples(samples: &mut [Duration], allocations: Allocations) -> Self {
+        assert!(!samples.is_empty(), "no samples to summarise");
+        samples.sort();
+        let rank = |p: f64| ((p * samples.len() as f64).ceil() as usize).clamp(1, samples.len()) - 1;
+        Stats {
+            min: samples[0],
+            median: samples[rank(0.5)],
+            p95: samples[rank(0.95)],
+            allocations,
+        }
+    }
+}
+
+/// Runs `day` against `input` once to warm up and then `iterations` more
+/// times, summarising each phase.
+pub fn bench_day(day: &Day, input: &str, iterations: usize) -> Result<Vec<(Phase, Stats)>, ParseError> {
+    day.run(input, &Part::BOTH)?;
+
+    let mut samples: [Vec<Duration>; 3] = Default::default();
+    let mut allocations = [Allocations::default(); 3];
+    for _ in 0..iterations {
+        let run = day.run(input, &Part::BOTH)?;
+        samples[0].push(run.parse_time);
+        allocations[0] = run.parse_allocations;
+        for (i, part) in run.parts.iter().enumerate() {
+            samples[i + 1].push(part.solve_time);
+            allocations[i + 1] = part.allocations;
+        }
+    }
+
+    Ok(Phase::ALL
+        .iter()
+        .zip(samples.iter_mut().zip(allocations))
+        .map(|(&phase, (samples, allocations))| (phase, Stats::from_samples(samples, allocations)))
+        .collect())
+}
+
+/// Identifies one benchmarked phase: a day, the phase and the input it ran on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Key {
+    pub day: u8,
+    pub phase: Phase,
+    pub input_hash: u64,
+}
+
+/// Saved statistics that later runs are compared against, stored one per
+/// line as `day phase input_hash min_ns median_ns p95_ns allocations bytes`.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Baseline {
+    entries: BTreeMap<Key, Stats>,
+}
+
+impl Baseline {
+    /// Loads the baseline at `path`, treating a missing file as empty.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(text) => Baseline::parse(&text).map_err(|(line, msg)| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}:{}: {}", path.display(), line, msg),
+                )
+            }),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Baseline::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the file format, reporting the 1-based line of the first error.
+    pub fn parse(text: &str) -> Result<Self, (usize, String)> {
+        let mut entries = BTreeMap::new();
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let error = |msg: String| (index + 1, msg);
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() != 8 {
+                return Err(error("expected 8 fields".into()));
+            }
+            let number = |field: &str| {
+                field
+                    .parse::<u64>()
+                    .map_err(|_| error(format!("invalid number '{}'", field)))
+            };
+            let key = Key {
+                day: fields[0].parse().map_err(|_| error(format!("invalid day '{}'", fields[0])))?,
+                phase: fields[1].parse().map_err(error)?,
+                input_hash: u64::from_str_radix(fields[2], 16)
+                    .map_err(|_| error(format!("invalid input hash '{}'", fields[2])))?,
+            };
+            let stats = Stats {
+                min: Duration::from_nanos(number(fields[3])?),
+                median: Duration::from_nanos(number(fields[4])?),
+                p95: Duration::from_nanos(number(fields[5])?),
+                allocations: Allocations {
+                    count: number(fields[6])?,
+                    bytes: number(fields[7])?,
+                },
+            };
+            entries.insert(key, stats);
+        }
+        Ok(Baseline { entries })
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    pub fn get(&self, key: &Key) -> Option<&Stats> {
+        self.entries.get(key)
+    }
+
+    pub fn insert(&mut self, key: Key, stats: Stats) {
+        self.entries.insert(key, stats);
+    }
+}
+
+impl fmt::Display for Baseline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# day phase input_hash min_ns median_ns p95_ns allocations bytes")?;
+        for (key, stats) in &self.entries {
+            writeln!(
+                f,
+                "{} {} {:016x} {} {} {} {} {}",
+                key.day,
+                key.phase,
+                key.input_hash,
+                stats.min.as_nanos(),
+                stats.median.as_nanos(),
+                stats.p95.as_nanos(),
+                stats.allocations.count,
+                stats.allocations.bytes
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Percentage change of the median from `baseline` to `current`.
+pub fn change(current: &Stats, baseline: &Stats) -> f64 {
+    let before = baseline.median.as_secs_f64();
+    if before == 0.0 {
+        return 0.0;
+    }
+    (current.median.as_secs_f64() - before) / before * 100.0
+}
+
+/// Column headings matching [`row`].
+pub fn header() -> String {
+    format!(
+        "{:>3}  {:<5}  {:>9}  {:>9}  {:>9}  {:>9}  {:>10}  baseline",
+        "day", "phase", "min", "median", "p95", "allocs", "bytes"
+    )
+}
+
+/// One table row, with the change against the baseline if there is one.
+pub fn row(key: &Key, stats: &Stats, change: Option<f64>) -> String {
+    let change = match change {
+        Some(pct) => format!("{:+.1}%", pct),
+        None => "-".to_string(),
+    };
+    format!(
+        "{:>3}  {:<5}  {:>9}  {:>9}  {:>9}  {:>9}  {:>10}  {}",
+        key.day,
+        key.phase,
+        format_duration(stats.min),
+        format_duration(stats.median),
+        format_duration(stats.p95),
+        stats.allocations.count,
+        format_bytes(stats.allocations.bytes),
+        change
+    )
+}
+
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit + 1 < UNITS.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn test_stats_from_samples() {
+        let mut samples: Vec<Duration> = (1..=20).rev().map(ms).collect();
+        let stats = Stats::from_samples(&mut samples, Allocations::default());
+        assert_eq!(stats.min, ms(1));
+        assert_eq!(stats.median, ms(10));
+        assert_eq!(stats.p95, ms(19));
+
+        let stats = Stats::from_samples(&mut [ms(7)], Allocations::default());
+        assert_eq!((stats.min, stats.median, stats.p95), (ms(7), ms(7), ms(7)));
+    }
+
+    #[test]
+    fn test_baseline_round_trip() {
+        let mut baseline = Baseline::default();
+        let key = Key {
+            day: 12,
+            phase: Phase::Part1,
+            input_hash: 0x1f,
+        };
+        let stats = Stats {
+            min: ms(1),
+            median: ms(2),
+            p95: ms(3),
+            allocations: Allocations { count: 4, bytes: 512 },
+        };
+        baseline.insert(key, stats);
+
+        let text = baseline.to_string();
+        assert!(text.ends_with("12 part1 000000000000001f 1000000 2000000 3000000 4 512\n"));
+        let parsed = Baseline::parse(&text).unwrap();
+        assert_eq!(parsed.get(&key), Some(&stats));
+        assert_eq!(Baseline::parse("1 parse 0 1 2").unwrap_err().0, 1);
+        let err = Baseline::parse("# days\n300 part1 1f 1 2 3 4 5").unwrap_err();
+        assert_eq!(err, (2, "invalid day '300'".to_string()));
+    }
+
+    #[test]
+    fn test_change() {
+        let stats = |median| Stats {
+            min: median,
+            median,
+            p95: median,
+            allocations: Allocations::default(),
+        };
+        assert_eq!(change(&stats(ms(110)), &stats(ms(100))).round(), 10.0);
+        assert_eq!(change(&stats(ms(50)), &stats(ms(100))).round(), -50.0);
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+}
@@ -0,0 +1,188 @@
+use crate::alloc::{self, Allocations};
+use aoc_core::gen::{Generator, Knob, Knobs};
+use aoc_core::input::{self, EXAMPLE_FILE, INPUT_FILE};
+use aoc_core::oracle::Check;
+use aoc_core::parse::ParseError;
+use aoc_core::rng::Rng;
+use aoc_core::solution::{self, Detail, Part, Solution};
+use aoc_core::Answer;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// The outcome of solving one day against one input.
+#[derive(Debug)]
+pub struct Run {
+    pub day: u8,
+    pub input_hash: u64,
+    pub parse_time: Duration,
+    pub parse_allocations: Allocations,
+    pub parts: Vec<PartRun>,
+}
+
+/// The answer to one part, what it cost and any supporting details.
+#[derive(Debug)]
+pub struct PartRun {
+    pub part: Part,
+    pub answer: Answer,
+    pub solve_time: Duration,
+    pub allocations: Allocations,
+    pub details: Vec<(&'static str, Detail)>,
+}
+
+/// Parses an input and answers the requested parts.
+type Runner = fn(&str, &[Part]) -> Result<Run, ParseError>;
+
+/// A day the runner knows how to solve.
+pub struct Day {
+    pub day: u8,
+    /// The day's directory, relative to the workspace root.
+    pub dir: &'static str,
+    /// Size knobs of the day's input generator.
+    pub knobs: &'static [Knob],
+    run: Runner,
+    generate: fn(&mut Rng, &Knobs) -> String,
+}
+
+impl Day {
+    pub fn input_path(&self) -> PathBuf {
+        workspace_root().join(self.dir).join(INPUT_FILE)
+    }
+
+    pub fn example_path(&self) -> PathBuf {
+        workspace_root().join(self.dir).join(EXAMPLE_FILE)
+    }
+
+    /// Parses `input` once and answers the requested parts in order.
+    pub fn run(&self, input: &str, parts: &[Part]) -> Result<Run, ParseError> {
+        (self.run)(input, parts)
+    }
+
+    /// Makes up a random input from `seed`, sized by `knobs`.
+    pub fn generate(&self, seed: u64, knobs: &Knobs) -> String {
+        (self.generate)(&mut Rng::new(seed), knobs)
+    }
+}
+
+fn run<S: Solution>(input: &str, parts: &[Part]) -> Result<Run, ParseError> {
+    let (parsed, parse_time, parse_allocations) = alloc::measure(|| S::parse(input));
+    let parsed = parsed?;
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let (answer, solve_time, allocations) =
+                alloc::measure(|| solution::solve_part::<S>(&parsed, part));
+            PartRun {
+                part,
+                answer,
+                solve_time,
+                allocations,
+                details: S::details(&parsed, part),
+            }
+        })
+        .collect();
+
+    Ok(Run {
+        day: S::DAY,
+        input_hash: input::hash(input),
+        parse_time,
+        parse_allocations,
+        parts,
+    })
+}
+
+pub fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("runner crate lives inside the workspace")
+}
+
+macro_rules! register {
+    ($($dir:literal => $solution:ty),* $(,)?) => {
+        &[$(Day {
+            day: <$solution as Solution>::DAY,
+            dir: $dir,
+            knobs: <$solution as Generator>::KNOBS,
+            run: run::<$solution>,
+            generate: <$solution as Generator>::generate,
+        }),*]
+    };
+}
+
+/// Every Rust day, in puzzle order.
+pub const DAYS: &[Day] = register! {
+    "day-2" => day_2::Day2,
+    "day-3" => day_3::Day3,
+    "day-4" => day_4::Day4,
+    "day-5" => day_5::Day5,
+    "day-6" => day_6::Day6,
+    "day-7" => day_7::Day7,
+    "day-8" => day_8::Day8,
+    "day-9" => day_9::Day9,
+    "day-10" => day_10::Day10,
+    "day-11" => day_11::Day11,
+    "day-12" => day_12::Day12,
+};
+
+/// Builds a day's differential checks against its reference solvers.
+pub type Oracles = fn() -> Vec<Box<dyn Check>>;
+
+/// The days with slow reference solvers to check their fast ones against.
+pub const ORACLES: &[(u8, Oracles)] = &[
+    (2, day_2::reference::oracles),
+    (3, day_3::reference::oracles),
+    (7, day_7::reference::oracles),
+    (9, day_9::reference::oracles),
+    (10, day_10::reference::oracles),
+];
+
+pub fn find(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_days_are_in_order_and_match_their_directory() {
+        assert!(DAYS.windows(2).all(|pair| pair[0].day < pair[1].day));
+        for day in DAYS {
+            assert_eq!(day.dir, format!("day-{}", day.day));
+            assert!(day.input_path().exists(), "{} has no input", day.dir);
+        }
+        assert!(ORACLES.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        assert!(ORACLES.iter().all(|&(day, _)| find(day).is_some()));
+    }
+
+    #[test]
+    fn test_run_dispatches_to_the_solution() {
+        let day = find(7).unwrap();
+        let input = std::fs::read_to_string(day.example_path()).unwrap();
+        let run = day.run(&input, &[Part::Two]).unwrap();
+        assert_eq!(run.day, 7);
+        assert_eq!(run.input_hash, input::hash(&input));
+        assert_eq!(run.parts.len(), 1);
+        assert_eq!(run.parts[0].part, Part::Two);
+        assert_eq!(run.parts[0].answer, Answer::Unsigned(40));
+    }
+
+    #[test]
+    fn test_generated_inputs_parse() {
+        for day in DAYS {
+            for seed in 0..3 {
+                let text = day.generate(seed, &Knobs::new(day.knobs));
+                if let Err(err) = day.run(&text, &[]) {
+                    panic!("{} seed {}: {}\n{}", day.dir, seed, err, err.render(&text, "generated"));
+                }
+            }
+            let knobs = Knobs::new(day.knobs);
+            assert_eq!(day.generate(7, &knobs), day.generate(7, &knobs), "{} is not reproducible", day.dir);
+        }
+    }
+
+    #[test]
+    fn test_run_reports_parse_errors() {
+        let err = find(2).unwrap().run("11-22,95-x", &Part::BOTH).unwrap_err();
+        assert_eq!((err.line, err.column, err.snippet.as_str()), (1, 10, "x"));
+    }
+}
@@ -0,0 +1,400 @@
+mod alloc;
+mod bench;
+mod days;
+mod report;
+mod verify;
+
+use alloc::CountingAlloc;
+use aoc_core::cli::{ArgError, Args};
+use aoc_core::gen::Knobs;
+use aoc_core::{input, Answer, Part};
+use bench::{Baseline, Key as BenchKey};
+use days::{Day, Run};
+use report::{Format, Reporter};
+use verify::{Answers, Summary};
+use std::error::Error;
+use std::fs;
+use std::panic;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+#[global_allocator]
+static ALLOCATOR: CountingAlloc = CountingAlloc;
+
+const USAGE: &str = "\
+Usage: aoc <command> [options]
+
+Commands:
+  run --day N [--part 1|2] [--input PATH|-] [--example] [--format F]
+      Solve one day. Reads PATH, standard input for '-', the day's
+      test_input.txt with --example, or its input.txt by default.
+  all [--example] [--format F]
+      Solve every day in order.
+  verify [--day N] [--answers PATH] [--record]
+      Check every day's input.txt and test_input.txt against the recorded
+      answers (answers.txt at the workspace root). Exits nonzero on any
+      mismatch, missing answer or regression. --record stores the current
+      answers instead.
+  bench [--day N] [--example] [--iterations N] [--threshold PCT]
+        [--baseline PATH] [--save-baseline]
+      Time each day's parse, part 1 and part 2 over many iterations
+      (default 10) and report min/median/p95 with allocations. Medians
+      more than PCT percent (default 10) slower than the saved baseline
+      (bench-baseline.txt at the workspace root) are flagged and make the
+      command fail. --save-baseline stores the results as the new baseline.
+  gen --day N [--seed S] [--KNOB VALUE ...] [--output PATH] [--knobs]
+      Print a random but valid input for day N, the same for the same seed
+      (default 1). Each day has its own size knobs; --knobs lists them with
+      their defaults. --output writes the input to PATH instead.
+  check [--day N] [--cases N] [--seed S]
+      Compare the fast solvers of days with a slow reference solver
+      against it on N small random cases (default 1000), seeded S, S+1, ...
+      (default 1). A disagreement is shrunk to a small input and reported
+      with the seed that reproduces it.
+  help
+      Show this message.
+
+Formats (--format):
+  text  Answers with timings and details (default)
+  json  One JSON object per day and part, one per line
+  csv   day,part,answer,parse_ns,solve_ns,input_hash,details";
+
+/// Where a day's puzzle input comes from.
+enum Source {
+    Input,
+    Example,
+    Stdin,
+    File(PathBuf),
+}
+
+impl Source {
+    fn from_args(args: &mut Args) -> Result<Self, ArgError> {
+        let example = args.flag("--example");
+        match (args.value("--input")?, example) {
+            (Some(_), true) => Err(ArgError("--input and --example cannot be combined".into())),
+            (Some(path), false) if path == "-" => Ok(Source::Stdin),
+            (Some(path), false) => Ok(Source::File(path.into())),
+            (None, true) => Ok(Source::Example),
+            (None, false) => Ok(Source::Input),
+        }
+    }
+
+    fn read(&self, day: &Day) -> Result<String, Box<dyn Error>> {
+        let text = match self {
+            Source::Input => input::read_input(day.input_path())?,
+            Source::Example => {
+                let path = day.example_path();
+                if !path.exists() {
+                    return Err(format!("day {} has no example input ({})", day.day, path.display()).into());
+                }
+                input::read_input(path)?
+            }
+            Source::Stdin => input::read_stdin()?,
+            Source::File(path) => input::read_input(path)?,
+        };
+        Ok(text)
+    }
+
+    /// How diagnostics name this source: a path relative to the workspace
+    /// root where there is one.
+    fn origin(&self, day: &Day) -> String {
+        match self {
+            Source::Input => format!("{}/{}", day.dir, input::INPUT_FILE),
+            Source::Example => format!("{}/{}", day.dir, input::EXAMPLE_FILE),
+            Source::Stdin => "<stdin>".to_string(),
+            Source::File(path) => path.display().to_string(),
+        }
+    }
+}
+
+/// Parses and solves `parts` of `day`, printing a diagnostic for input that
+/// does not parse.
+fn solve(day: &Day, source: &Source, text: &str, parts: &[Part]) -> Result<Run, String> {
+    day.run(text, parts).map_err(|err| {
+        let origin = source.origin(day);
+        eprintln!("{}", err.render(text, &origin));
+        format!("could not parse {}", origin)
+    })
+}
+
+fn main() -> ExitCode {
+    match run(Args::from_env()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(mut args: Args) -> Result<(), Box<dyn Error>> {
+    match args.subcommand().as_deref() {
+        Some("run") => run_day(args),
+        Some("all") => run_all(args),
+        Some("verify") => run_verify(args),
+        Some("bench") => run_bench(args),
+        Some("gen") => run_gen(args),
+        Some("check") => run_check(args),
+        Some("help") | None => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+        Some(other) => Err(format!("unknown command '{}'\n\n{}", other, USAGE).into()),
+    }
+}
+
+fn run_day(mut args: Args) -> Result<(), Box<dyn Error>> {
+    let day: u8 = args
+        .parsed("--day")?
+        .ok_or_else(|| ArgError("run needs --day".into()))?;
+    let part: Option<Part> = args.parsed("--part")?;
+    let format = args.parsed("--format")?.unwrap_or(Format::Text);
+    let source = Source::from_args(&mut args)?;
+    args.finish()?;
+
+    let day = days::find(day).ok_or_else(|| format!("no solution for day {}", day))?;
+    let parts = match part {
+        Some(part) => vec![part],
+        None => Part::BOTH.to_vec(),
+    };
+
+    let text = source.read(day)?;
+    Reporter::new(format).report(&solve(day, &source, &text, &parts)?);
+    Ok(())
+}
+
+fn run_all(mut args: Args) -> Result<(), Box<dyn Error>> {
+    let format = args.parsed("--format")?.unwrap_or(Format::Text);
+    let source = Source::from_args(&mut args)?;
+    if matches!(source, Source::Stdin | Source::File(_)) {
+        return Err(ArgError("all reads each day's own input; use --example or nothing".into()).into());
+    }
+    args.finish()?;
+
+    let mut reporter = Reporter::new(format);
+    for day in days::DAYS {
+        let text = match source.read(day) {
+            Ok(text) => text,
+            Err(err) => {
+                reporter.skip(day.day, &err.to_string());
+                continue;
+            }
+        };
+        match solve(day, &source, &text, &Part::BOTH) {
+            Ok(run) => reporter.report(&run),
+            Err(reason) => reporter.skip(day.day, &reason),
+        }
+    }
+    Ok(())
+}
+
+fn run_verify(mut args: Args) -> Result<(), Box<dyn Error>> {
+    let only: Option<u8> = args.parsed("--day")?;
+    let path = match args.value("--answers")? {
+        Some(path) => PathBuf::from(path),
+        None => days::workspace_root().join(verify::ANSWERS_FILE),
+    };
+    let record = args.flag("--record");
+    args.finish()?;
+
+    if let Some(day) = only {
+        days::find(day).ok_or_else(|| format!("no solution for day {}", day))?;
+    }
+
+    let mut answers = Answers::load(&path)?;
+    let mut recorded = 0;
+    let mut summary = Summary::default();
+
+    for day in days::DAYS.iter().filter(|d| only.is_none_or(|n| d.day == n)) {
+        for input_path in verify::inputs(day) {
+            let text = input::read_input(&input_path)?;
+            let name = input_path.file_name().unwrap_or_default().to_string_lossy();
+            let outcome = verify::run_guarded(day, &text);
+
+            for part in Part::BOTH {
+                let key = verify::key(day, part, &text);
+                let actual = match &outcome {
+                    Ok(run) => Ok(&run.parts[part.number() as usize - 1].answer),
+                    Err(reason) => Err(reason.as_str()),
+                };
+
+                if record {
+                    if let Ok(answer) = actual {
+                        if *answer != Answer::Unsolved {
+                            answers.insert(key, answer.to_string());
+                            recorded += 1;
+                        }
+                    }
+                    continue;
+                }
+
+                let status = verify::check(answers.get(&key), actual);
+                println!("{}", status.describe(&format!("day {} part {} {}", day.day, part, name)));
+                summary.add(&status);
+            }
+        }
+    }
+
+    if record {
+        answers.save(&path)?;
+        println!("recorded {} answers in {} ({} total)", recorded, path.display(), answers.len());
+        return Ok(());
+    }
+
+    println!("\n{}", summary);
+    let failures = summary.failures();
+    if failures > 0 {
+        return Err(format!("verification failed for {} answers", failures).into());
+    }
+    Ok(())
+}
+
+fn run_bench(mut args: Args) -> Result<(), Box<dyn Error>> {
+    let only: Option<u8> = args.parsed("--day")?;
+    let iterations: usize = args.parsed("--iterations")?.unwrap_or(10);
+    let threshold: f64 = args.parsed("--threshold")?.unwrap_or(10.0);
+    let path = match args.value("--baseline")? {
+        Some(path) => PathBuf::from(path),
+        None => days::workspace_root().join(bench::BASELINE_FILE),
+    };
+    let save = args.flag("--save-baseline");
+    let source = Source::from_args(&mut args)?;
+    if matches!(source, Source::Stdin | Source::File(_)) {
+        return Err(ArgError("bench reads each day's own input; use --example or nothing".into()).into());
+    }
+    args.finish()?;
+
+    if iterations == 0 {
+        return Err(ArgError("--iterations must be at least 1".into()).into());
+    }
+    if let Some(day) = only {
+        days::find(day).ok_or_else(|| format!("no solution for day {}", day))?;
+    }
+
+    let mut baseline = Baseline::load(&path)?;
+    let mut regressions = 0;
+
+    println!("{}", bench::header());
+    for day in days::DAYS.iter().filter(|d| only.is_none_or(|n| d.day == n)) {
+        let text = match source.read(day) {
+            Ok(text) => text,
+            Err(err) => {
+                eprintln!("day {} skipped: {}", day.day, err);
+                continue;
+            }
+        };
+        let input_hash = input::hash(&text);
+
+        let phases = match bench::bench_day(day, &text, iterations) {
+            Ok(phases) => phases,
+            Err(err) => {
+                let origin = source.origin(day);
+                eprintln!("{}", err.render(&text, &origin));
+                eprintln!("day {} skipped: could not parse {}", day.day, origin);
+                continue;
+            }
+        };
+        for (phase, stats) in phases {
+            let key = BenchKey {
+                day: day.day,
+                phase,
+                input_hash,
+            };
+            let change = baseline.get(&key).map(|before| bench::change(&stats, before));
+            let mut line = bench::row(&key, &stats, change);
+            if change.is_some_and(|pct| pct > threshold) {
+                line.push_str("  REGRESSION");
+                regressions += 1;
+            }
+            println!("{}", line);
+            if save {
+                baseline.insert(key, stats);
+            }
+        }
+    }
+
+    if save {
+        baseline.save(&path)?;
+        println!("saved baseline to {}", path.display());
+    }
+    if regressions > 0 {
+        return Err(format!(
+            "{} phases are more than {}% slower than the baseline",
+            regressions, threshold
+        )
+        .into());
+    }
+    Ok(())
+}
+
+fn run_gen(mut args: Args) -> Result<(), Box<dyn Error>> {
+    let day: u8 = args
+        .parsed("--day")?
+        .ok_or_else(|| ArgError("gen needs --day".into()))?;
+    let day = days::find(day).ok_or_else(|| format!("no solution for day {}", day))?;
+    let seed: u64 = args.parsed("--seed")?.unwrap_or(1);
+    let output = args.value("--output")?;
+    let list = args.flag("--knobs");
+    let mut knobs = Knobs::new(day.knobs);
+    for knob in day.knobs {
+        if let Some(value) = args.parsed(&format!("--{}", knob.name))? {
+            knobs.set(knob.name, value);
+        }
+    }
+    args.finish()
+        .map_err(|err| format!("{} (see `aoc gen --day {} --knobs`)", err, day.day))?;
+
+    if list {
+        for knob in day.knobs {
+            println!("--{:<10} {:>16}  {}", knob.name, knob.default, knob.help);
+        }
+        return Ok(());
+    }
+
+    let text = day.generate(seed, &knobs);
+    match output {
+        Some(path) => fs::write(&path, text).map_err(|err| format!("failed to write {}: {}", path, err))?,
+        None => print!("{}", text),
+    }
+    Ok(())
+}
+
+fn run_check(mut args: Args) -> Result<(), Box<dyn Error>> {
+    let day: Option<u8> = args.parsed("--day")?;
+    let cases: u64 = args.parsed("--cases")?.unwrap_or(1000);
+    let seed: u64 = args.parsed("--seed")?.unwrap_or(1);
+    args.finish()?;
+
+    let oracles: Vec<_> = days::ORACLES
+        .iter()
+        .filter(|&&(d, _)| day.is_none_or(|day| day == d))
+        .collect();
+    if oracles.is_empty() {
+        return Err(format!("no reference solver for day {}", day.unwrap_or_default()).into());
+    }
+
+    // Solvers that panic are reported as mismatches; keep their messages
+    // from interleaving with the report.
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let mut failed = 0;
+    for &(day, build) in oracles {
+        for check in build() {
+            match check.run(seed, cases) {
+                Ok(()) => println!("day {:>2}  {:<32} ok ({} cases)", day, check.name(), cases),
+                Err(mismatch) => {
+                    failed += 1;
+                    println!("day {:>2}  {:<32} FAILED", day, check.name());
+                    println!("{}", mismatch);
+                    println!("reproduce with `aoc check --day {} --seed {} --cases 1`", day, mismatch.seed);
+                }
+            }
+        }
+    }
+    panic::set_hook(hook);
+
+    match failed {
+        0 => Ok(()),
+        n => Err(format!("{} check(s) found a mismatch", n).into()),
+    }
+}
@@ -0,0 +1,262 @@
+use crate::days::Run;
+use aoc_core::solution::Detail;
+use aoc_core::timing::format_duration;
+use aoc_core::Answer;
+use std::fmt::Write;
+use std::str::FromStr;
+
+/// How answers are printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Human-readable, one block per day.
+    Text,
+    /// One JSON object per line for every day and part.
+    Json,
+    /// A header row followed by one row for every day and part.
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("unknown format '{}', expected text, json or csv", s)),
+        }
+    }
+}
+
+const CSV_HEADER: &str = "day,part,answer,parse_ns,solve_ns,input_hash,details";
+
+/// Prints runs as they complete in the chosen format.
+pub struct Reporter {
+    format: Format,
+    wrote_header: bool,
+}
+
+impl Reporter {
+    pub fn new(format: Format) -> Self {
+        Reporter {
+            format,
+            wrote_header: false,
+        }
+    }
+
+    pub fn report(&mut self, run: &Run) {
+        match self.format {
+            Format::Text => print!("{}", text(run)),
+            Format::Json => print!("{}", json_lines(run)),
+            Format::Csv => {
+                if !self.wrote_header {
+                    println!("{}", CSV_HEADER);
+                    self.wrote_header = true;
+                }
+                print!("{}", csv_rows(run));
+            }
+        }
+    }
+
+    /// Notes a day that could not be run. Structured formats leave it out so
+    /// that every record they contain is a real answer.
+    pub fn skip(&mut self, day: u8, reason: &str) {
+        match self.format {
+            Format::Text => println!("Day {}\n  skipped: {}", day, reason),
+            Format::Json | Format::Csv => eprintln!("day {} skipped: {}", day, reason),
+        }
+    }
+}
+
+pub fn text(run: &Run) -> String {
+    let mut out = format!("Day {} (parse {})\n", run.day, format_duration(run.parse_time));
+    for part in &run.parts {
+        let _ = writeln!(
+            out,
+            "  Part {}: {} ({})",
+            part.part,
+            part.answer,
+            format_duration(part.solve_time)
+        );
+        for (name, detail) in &part.details {
+            let _ = writeln!(out, "    {}: {}", name, detail);
+        }
+    }
+    out
+}
+
+pub fn json_lines(run: &Run) -> String {
+    let mut out = String::new();
+    for part in &run.parts {
+        let details: Vec<String> = part
+            .details
+            .iter()
+            .map(|(name, detail)| format!("{}:{}", json_string(name), json_detail(detail)))
+            .collect();
+        let _ = writeln!(
+            out,
+            "{{\"day\":{},\"part\":{},\"answer\":{},\"parse_ns\":{},\"solve_ns\":{},\"input_hash\":\"{:016x}\",\"details\":{{{}}}}}",
+            run.day,
+            part.part,
+            json_answer(&part.answer),
+            run.parse_time.as_nanos(),
+            part.solve_time.as_nanos(),
+            run.input_hash,
+            details.join(",")
+        );
+    }
+    out
+}
+
+pub fn csv_rows(run: &Run) -> String {
+    let mut out = String::new();
+    for part in &run.parts {
+        let details: Vec<String> = part
+            .details
+            .iter()
+            .map(|(name, detail)| match detail {
+                Detail::List(values) => {
+                    let values: Vec<String> = values.iter().map(i64::to_string).collect();
+                    format!("{}={}", name, values.join(" "))
+                }
+                _ => format!("{}={}", name, detail),
+            })
+            .collect();
+        let answer = match &part.answer {
+            Answer::Unsolved => String::new(),
+            answer => answer.to_string(),
+        };
+        let _ = writeln!(
+            out,
+            "{},{},{},{},{},{:016x},{}",
+            run.day,
+            part.part,
+            csv_field(&answer),
+            run.parse_time.as_nanos(),
+            part.solve_time.as_nanos(),
+            run.input_hash,
+            csv_field(&details.join(";"))
+        );
+    }
+    out
+}
+
+/// Answers are emitted as strings: several exceed the 2^53 integers that
+/// JSON readers can represent exactly.
+fn json_answer(answer: &Answer) -> String {
+    match answer {
+        Answer::Unsolved => "null".to_string(),
+        answer => json_string(&answer.to_string()),
+    }
+}
+
+fn json_detail(detail: &Detail) -> String {
+    match detail {
+        Detail::Int(n) => n.to_string(),
+        Detail::List(values) => {
+            let values: Vec<String> = values.iter().map(i64::to_string).collect();
+            format!("[{}]", values.join(","))
+        }
+        Detail::Text(s) => json_string(s),
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alloc::Allocations;
+    use crate::days::PartRun;
+    use aoc_core::Part;
+    use std::time::Duration;
+
+    fn sample_run() -> Run {
+        Run {
+            day: 8,
+            input_hash: 0xabc,
+            parse_time: Duration::from_nanos(1500),
+            parse_allocations: Allocations::default(),
+            parts: vec![
+                PartRun {
+                    part: Part::One,
+                    answer: Answer::Unsigned(42315),
+                    solve_time: Duration::from_nanos(20),
+                    allocations: Allocations::default(),
+                    details: vec![
+                        ("circuits", Detail::Int(287)),
+                        ("top_circuit_sizes", Detail::List(vec![39, 35, 31])),
+                    ],
+                },
+                PartRun {
+                    part: Part::Two,
+                    answer: Answer::Unsolved,
+                    solve_time: Duration::from_nanos(5),
+                    allocations: Allocations::default(),
+                    details: Vec::new(),
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_json_lines() {
+        let expected = concat!(
+            "{\"day\":8,\"part\":1,\"answer\":\"42315\",\"parse_ns\":1500,\"solve_ns\":20,",
+            "\"input_hash\":\"0000000000000abc\",\"details\":{\"circuits\":287,\"top_circuit_sizes\":[39,35,31]}}\n",
+            "{\"day\":8,\"part\":2,\"answer\":null,\"parse_ns\":1500,\"solve_ns\":5,",
+            "\"input_hash\":\"0000000000000abc\",\"details\":{}}\n",
+        );
+        assert_eq!(json_lines(&sample_run()), expected);
+    }
+
+    #[test]
+    fn test_csv_rows() {
+        let expected = concat!(
+            "8,1,42315,1500,20,0000000000000abc,circuits=287;top_circuit_sizes=39 35 31\n",
+            "8,2,,1500,5,0000000000000abc,\n",
+        );
+        assert_eq!(csv_rows(&sample_run()), expected);
+    }
+
+    #[test]
+    fn test_escaping() {
+        assert_eq!(json_string("a\"b\\c\n"), "\"a\\\"b\\\\c\\n\"");
+        assert_eq!(csv_field("x,y"), "\"x,y\"");
+        assert_eq!(csv_field("say \"hi\", ok"), "\"say \"\"hi\"\", ok\"");
+        assert_eq!(csv_field("a\rb"), "\"a\rb\"");
+    }
+
+    #[test]
+    fn test_format_from_str() {
+        assert_eq!("csv".parse::<Format>(), Ok(Format::Csv));
+        assert!("xml".parse::<Format>().is_err());
+    }
+}
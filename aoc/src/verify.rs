@@ -0,0 +1,282 @@
+use crate::days::{Day, Run};
+use aoc_core::input;
+use aoc_core::{Answer, Part};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::panic;
+use std::path::{Path, PathBuf};
+
+/// File name of the recorded answers, kept at the workspace root.
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+/// Identifies one recorded answer: a day, a part and the input it was
+/// computed from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Key {
+    pub day: u8,
+    pub part: Part,
+    pub input_hash: u64,
+}
+
+/// Known-good answers, stored one per line as `day part input_hash answer`.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    entries: BTreeMap<Key, String>,
+}
+
+impl Answers {
+    /// Loads the answers at `path`, treating a missing file as empty.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(text) => Answers::parse(&text).map_err(|(line, msg)| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}:{}: {}", path.display(), line, msg),
+                )
+            }),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the file format, reporting the 1-based line of the first error.
+    pub fn parse(text: &str) -> Result<Self, (usize, String)> {
+        let mut entries = BTreeMap::new();
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields: Vec<&str> = line.splitn(4, ' ').collect();
+            if fields.len() != 4 {
+                return Err((index + 1, "expected 'day part input_hash answer'".into()));
+            }
+            let day = fields[0]
+                .parse()
+                .map_err(|_| (index + 1, format!("invalid day '{}'", fields[0])))?;
+            let part = fields[1].parse().map_err(|err| (index + 1, err))?;
+            let input_hash = u64::from_str_radix(fields[2], 16)
+                .map_err(|_| (index + 1, format!("invalid input hash '{}'", fields[2])))?;
+            entries.insert(Key { day, part, input_hash }, fields[3].to_string());
+        }
+        Ok(Answers { entries })
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    pub fn get(&self, key: &Key) -> Option<&str> {
+        self.entries.get(key).map(String::as_str)
+    }
+
+    pub fn insert(&mut self, key: Key, answer: String) {
+        self.entries.insert(key, answer);
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# day part input_hash answer")?;
+        for (key, answer) in &self.entries {
+            writeln!(f, "{} {} {:016x} {}", key.day, key.part, key.input_hash, answer)?;
+        }
+        Ok(())
+    }
+}
+
+/// How a freshly computed answer compares with the recorded one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    /// Matches the recorded answer.
+    Ok,
+    /// Differs from the recorded answer.
+    Mismatch { expected: String, actual: String },
+    /// Had a recorded answer but is now unsolved or the solver panicked.
+    Regression { expected: String, reason: String },
+    /// Produced an answer that has never been recorded.
+    Missing { actual: String },
+    /// Unsolved, and nothing was recorded either.
+    Unsolved,
+}
+
+impl Status {
+    /// One report line for the answer described by `label`.
+    pub fn describe(&self, label: &str) -> String {
+        match self {
+            Status::Ok => format!("ok          {}", label),
+            Status::Mismatch { expected, actual } => {
+                format!("MISMATCH    {}: expected {}, got {}", label, expected, actual)
+            }
+            Status::Regression { expected, reason } => {
+                format!("REGRESSION  {}: expected {}, {}", label, expected, reason)
+            }
+            Status::Missing { actual } => {
+                format!("MISSING     {}: no recorded answer (got {})", label, actual)
+            }
+            Status::Unsolved => format!("unsolved    {}", label),
+        }
+    }
+}
+
+/// Counts of each status over a verification run.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Summary {
+    pub ok: usize,
+    pub mismatched: usize,
+    pub regressed: usize,
+    pub missing: usize,
+    pub unsolved: usize,
+}
+
+impl Summary {
+    pub fn add(&mut self, status: &Status) {
+        let count = match status {
+            Status::Ok => &mut self.ok,
+            Status::Mismatch { .. } => &mut self.mismatched,
+            Status::Regression { .. } => &mut self.regressed,
+            Status::Missing { .. } => &mut self.missing,
+            Status::Unsolved => &mut self.unsolved,
+        };
+        *count += 1;
+    }
+
+    pub fn failures(&self) -> usize {
+        self.mismatched + self.regressed + self.missing
+    }
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} ok, {} mismatched, {} regressed, {} missing, {} unsolved",
+            self.ok, self.mismatched, self.regressed, self.missing, self.unsolved
+        )
+    }
+}
+
+/// Compares an outcome with the recorded answer for it. `actual` is `Err`
+/// when the solver panicked.
+pub fn check(recorded: Option<&str>, actual: Result<&Answer, &str>) -> Status {
+    match (recorded, actual) {
+        (Some(expected), Ok(Answer::Unsolved)) => Status::Regression {
+            expected: expected.to_string(),
+            reason: "now unsolved".to_string(),
+        },
+        (Some(expected), Err(reason)) => Status::Regression {
+            expected: expected.to_string(),
+            reason: reason.to_string(),
+        },
+        (Some(expected), Ok(answer)) if answer.to_string() == expected => Status::Ok,
+        (Some(expected), Ok(answer)) => Status::Mismatch {
+            expected: expected.to_string(),
+            actual: answer.to_string(),
+        },
+        (None, Ok(Answer::Unsolved)) => Status::Unsolved,
+        (None, Ok(answer)) => Status::Missing {
+            actual: answer.to_string(),
+        },
+        (None, Err(reason)) => Status::Regression {
+            expected: "an answer".to_string(),
+            reason: reason.to_string(),
+        },
+    }
+}
+
+/// The inputs a day is verified against: its puzzle input and, if it has
+/// one, the worked example.
+pub fn inputs(day: &Day) -> Vec<PathBuf> {
+    let mut paths = vec![day.input_path()];
+    let example = day.example_path();
+    if example.exists() {
+        paths.push(example);
+    }
+    paths
+}
+
+/// Runs both parts of `day`, turning a malformed input or a panicking solver
+/// into an error.
+pub fn run_guarded(day: &Day, text: &str) -> Result<Run, String> {
+    let outcome = panic::catch_unwind(|| day.run(text, &Part::BOTH)).map_err(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic".to_string());
+        format!("solver panicked: {}", message)
+    })?;
+    outcome.map_err(|err| format!("input rejected: {}", err))
+}
+
+/// The key for `part` of `day` run against `text`.
+pub fn key(day: &Day, part: Part, text: &str) -> Key {
+    Key {
+        day: day.day,
+        part,
+        input_hash: input::hash(text),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answers_round_trip() {
+        let mut answers = Answers::default();
+        let key = Key {
+            day: 7,
+            part: Part::Two,
+            input_hash: 0xbeef,
+        };
+        answers.insert(key, "40".to_string());
+
+        let text = answers.to_string();
+        assert_eq!(text, "# day part input_hash answer\n7 2 000000000000beef 40\n");
+        let parsed = Answers::parse(&text).unwrap();
+        assert_eq!(parsed, answers);
+        assert_eq!(parsed.get(&key), Some("40"));
+    }
+
+    #[test]
+    fn test_answers_parse_errors_name_the_line() {
+        assert_eq!(Answers::parse("\n7 3 00 1\n").unwrap_err().0, 2);
+        assert_eq!(Answers::parse("7 1 zz 1").unwrap_err().0, 1);
+        assert_eq!(Answers::parse("7 1").unwrap_err().0, 1);
+    }
+
+    #[test]
+    fn test_check() {
+        let forty = Answer::Unsigned(40);
+        assert_eq!(check(Some("40"), Ok(&forty)), Status::Ok);
+        assert!(matches!(check(Some("41"), Ok(&forty)), Status::Mismatch { .. }));
+        assert!(matches!(check(Some("40"), Ok(&Answer::Unsolved)), Status::Regression { .. }));
+        assert!(matches!(check(Some("40"), Err("boom")), Status::Regression { .. }));
+        assert!(matches!(check(None, Ok(&forty)), Status::Missing { .. }));
+        assert_eq!(check(None, Ok(&Answer::Unsolved)), Status::Unsolved);
+    }
+
+    #[test]
+    fn test_summary_counts_failures() {
+        let mut summary = Summary::default();
+        summary.add(&Status::Ok);
+        summary.add(&Status::Unsolved);
+        summary.add(&Status::Missing { actual: "1".into() });
+        summary.add(&Status::Mismatch {
+            expected: "1".into(),
+            actual: "2".into(),
+        });
+        assert_eq!(summary.failures(), 2);
+        assert_eq!(
+            summary.to_string(),
+            "1 ok, 1 mismatched, 0 regressed, 1 missing, 1 unsolved"
+        );
+    }
+}
@@ -0,0 +1,649 @@
+use aoc_core::gen::{Generator, Knob, Knobs};
+use aoc_core::parse::{self, ParseError};
+use aoc_core::rng::Rng;
+use aoc_core::{Answer, Solution};
+
+pub mod reference;
+
+/// One factory machine: its indicator light diagram, button wiring
+/// schematics and joltage requirements.
+#[derive(Debug)]
+pub struct Machine {
+    pub target: Vec<bool>,
+    pub buttons: Vec<Vec<usize>>,
+    pub joltage: Vec<usize>,
+}
+
+pub fn parse_machines(input: &str) -> Result<Vec<Machine>, ParseError> {
+    parse::lines(input).map(|line| parse_machine(input, line)).collect()
+}
+
+/// Total fewest presses for the lights, or `None` if some machine cannot
+/// reach its diagram.
+pub fn solve_part1(machines: &[Machine]) -> Option<usize> {
+    machines
+        .iter()
+        .map(|machine| find_min_presses_part1(&machine.target, &machine.buttons))
+        .sum()
+}
+
+/// Total fewest presses for the joltages, or `None` if some machine cannot
+/// reach its requirements.
+pub fn solve_part2(machines: &[Machine]) -> Option<usize> {
+    machines
+        .iter()
+        .map(|machine| find_min_presses_part2(&machine.joltage, &machine.buttons))
+        .sum()
+}
+
+/// Parses one machine description, `line`, a slice of `source`, such as
+/// `[.##.] (3) (1,3) (2) {3,5,4}`.
+pub fn parse_machine(source: &str, line: &str) -> Result<Machine, ParseError> {
+    // Parse indicator light diagram [.##.]
+    let Some(after_bracket) = line.trim_start().strip_prefix('[') else {
+        return Err(ParseError::at(source, line.trim(), "an indicator light diagram like `[.##.]`"));
+    };
+    let bracket_end = closing(source, after_bracket, ']', "`]` closing the light diagram")?;
+    let diagram = &after_bracket[..bracket_end];
+    if let Some(i) = diagram.find(|c| c != '.' && c != '#') {
+        let c = diagram[i..].chars().next().unwrap();
+        return Err(ParseError::at(source, &diagram[i..i + c.len_utf8()], "a light `.` or `#`"));
+    }
+    let target: Vec<bool> = diagram.chars().map(|c| c == '#').collect();
+    
+    // Parse button wiring schematics (x,y,z) up to the joltage requirements
+    let mut rest = &after_bracket[bracket_end + 1..];
+    let mut buttons = Vec::new();
+    while let Some(wiring) = rest.trim_start().strip_prefix('(') {
+        let end = closing(source, wiring, ')', "`)` closing the button wiring")?;
+        let mut indices = Vec::new();
+        for field in wiring[..end].split(',').filter(|f| !f.trim().is_empty()) {
+            let index: usize = parse::number(source, field, "a light index")?;
+            if index >= target.len() {
+                let expected = format!("a light index below {}", target.len());
+                return Err(ParseError::at(source, field.trim(), expected));
+            }
+            indices.push(index);
+        }
+        buttons.push(indices);
+        rest = &wiring[end + 1..];
+    }
+    
+    // Parse joltage requirements {x,y,z}
+    let rest = rest.trim_start();
+    let (joltage, rest) = match rest.strip_prefix('{') {
+        Some(requirements) => {
+            let end = closing(source, requirements, '}', "`}` closing the joltage requirements")?;
+            let joltage = parse::numbers(source, &requirements[..end], ',')?;
+            (joltage, requirements[end + 1..].trim())
+        }
+        None => (Vec::new(), rest.trim_end()),
+    };
+    if !rest.is_empty() {
+        return Err(ParseError::at(source, rest, "a button like `(0,2)` or joltage like `{3,5}`"));
+    }
+    
+    Ok(Machine { target, buttons, joltage })
+}
+
+/// Position of the `close` delimiter in `text`, a slice of `source`.
+fn closing(source: &str, text: &str, close: char, expected: &str) -> Result<usize, ParseError> {
+    text.find(close)
+        .ok_or_else(|| ParseError::at(source, &text[text.len()..], expected))
+}
+
+/// Fewest presses that light `target`, or `None` if no presses do.
+pub fn find_min_presses_part1(target: &[bool], buttons: &[Vec<usize>]) -> Option<usize> {
+    let num_lights = target.len();
+    let num_buttons = buttons.len();
+    
+    // Convert target to binary vector
+    let target_bits: Vec<u8> = target.iter().map(|&b| if b { 1 } else { 0 }).collect();
+    
+    // Build matrix where each column represents a button's effect
+    // Matrix is num_lights rows x num_buttons columns
+    let mut matrix: Vec<Vec<u8>> = vec![vec![0; num_buttons]; num_lights];
+    for (btn_idx, button) in buttons.iter().enumerate() {
+        for &light_idx in button {
+            if light_idx < num_lights {
+                matrix[light_idx][btn_idx] = 1;
+            }
+        }
+    }
+    
+    // We need to find x (button presses) such that matrix * x = target (mod 2)
+    // and minimize sum(x)
+    // Since each button press is binary (0 or 1 effective presses mod 2),
+    // we enumerate all 2^num_buttons possibilities
+    
+    if num_buttons <= 20 {
+        // Brute force for small number of buttons
+        find_min_by_enumeration(&matrix, &target_bits, num_buttons, num_lights)
+    } else {
+        // For larger number of buttons, use Gaussian elimination to find 
+        // the solution space, then search through it
+        find_min_with_gauss(&matrix, &target_bits, num_buttons, num_lights)
+    }
+}
+
+fn find_min_by_enumeration(
+    matrix: &[Vec<u8>],
+    target: &[u8],
+    num_buttons: usize,
+    num_lights: usize,
+) -> Option<usize> {
+    let mut min_presses = usize::MAX;
+    
+    for mask in 0u32..(1u32 << num_buttons) {
+        let presses = mask.count_ones() as usize;
+        if presses >= min_presses {
+            continue;
+        }
+        
+        // Check if this combination of button presses achieves the target
+        let mut result = vec![0u8; num_lights];
+        for btn in 0..num_buttons {
+            if (mask >> btn) & 1 == 1 {
+                for light in 0..num_lights {
+                    result[light] ^= matrix[light][btn];
+                }
+            }
+        }
+        
+        if result == target {
+            min_presses = presses;
+        }
+    }
+    
+    (min_presses != usize::MAX).then_some(min_presses)
+}
+
+fn find_min_with_gauss(
+    matrix: &[Vec<u8>],
+    target: &[u8],
+    num_buttons: usize,
+    num_lights: usize,
+) -> Option<usize> {
+    // Create augmented matrix [A|b]
+    let mut aug: Vec<Vec<u8>> = vec![vec![0; num_buttons + 1]; num_lights];
+    for row in 0..num_lights {
+        for col in 0..num_buttons {
+            aug[row][col] = matrix[row][col];
+        }
+        aug[row][num_buttons] = target[row];
+    }
+    
+    // Gaussian elimination in GF(2)
+    let mut pivot_cols = Vec::new();
+    let mut pivot_row = 0;
+    
+    for col in 0..num_buttons {
+        // Find pivot
+        let mut found = false;
+        for row in pivot_row..num_lights {
+            if aug[row][col] == 1 {
+                aug.swap(pivot_row, row);
+                found = true;
+                break;
+            }
+        }
+        
+        if !found {
+            continue;
+        }
+        
+        pivot_cols.push(col);
+        
+        // Eliminate
+        for row in 0..num_lights {
+            if row != pivot_row && aug[row][col] == 1 {
+                for c in 0..=num_buttons {
+                    aug[row][c] ^= aug[pivot_row][c];
+                }
+            }
+        }
+        
+        pivot_row += 1;
+    }
+    
+    // Check for inconsistency
+    for row in pivot_row..num_lights {
+        if aug[row][num_buttons] == 1 {
+            return None;
+        }
+    }
+    
+    // Find free variables
+    let pivot_set: std::collections::HashSet<usize> = pivot_cols.iter().copied().collect();
+    let free_vars: Vec<usize> = (0..num_buttons).filter(|c| !pivot_set.contains(c)).collect();
+    
+    let num_free = free_vars.len();
+    
+    // Enumerate all 2^num_free combinations of free variables
+    let mut min_presses = usize::MAX;
+    
+    for free_mask in 0u64..(1u64 << num_free) {
+        let mut solution = vec![0u8; num_buttons];
+        
+        // Set free variables
+        for (i, &var) in free_vars.iter().enumerate() {
+            solution[var] = ((free_mask >> i) & 1) as u8;
+        }
+        
+        // Back-substitute to find pivot variables
+        for (idx, &pivot_col) in pivot_cols.iter().enumerate().rev() {
+            let row = idx;
+            let mut val = aug[row][num_buttons];
+            for col in (pivot_col + 1)..num_buttons {
+                val ^= aug[row][col] * solution[col];
+            }
+            solution[pivot_col] = val;
+        }
+        
+        // Count presses
+        let presses: usize = solution.iter().map(|&x| x as usize).sum();
+        min_presses = min_presses.min(presses);
+    }
+    
+    (min_presses != usize::MAX).then_some(min_presses)
+}
+
+// Part 2: Find minimum button presses to reach joltage target (non-negative integers)
+// This is an Integer Linear Programming problem: minimize sum(x) subject to Ax = b, x >= 0
+pub fn find_min_presses_part2(target: &[usize], buttons: &[Vec<usize>]) -> Option<usize> {
+    let num_counters = target.len();
+    let num_buttons = buttons.len();
+    
+    // Build matrix A where A[i][j] = 1 if button j affects counter i
+    let mut matrix: Vec<Vec<i64>> = vec![vec![0; num_buttons]; num_counters];
+    for (btn_idx, button) in buttons.iter().enumerate() {
+        for &counter_idx in button {
+            if counter_idx < num_counters {
+                matrix[counter_idx][btn_idx] = 1;
+            }
+        }
+    }
+    
+    let target_i64: Vec<i64> = target.iter().map(|&x| x as i64).collect();
+    
+    // Use branch and bound with simplex-like relaxation
+    solve_ilp(&matrix, &target_i64, num_buttons, num_counters)
+}
+
+fn solve_ilp(matrix: &[Vec<i64>], target: &[i64], num_buttons: usize, num_counters: usize) -> Option<usize> {
+    // We'll use a different approach: enumerate solutions smartly
+    // Key insight: The problem is equivalent to finding non-negative integer
+    // solutions to a system of linear equations that minimizes sum.
+    
+    // Use Gaussian elimination to find the solution space, then search
+    // for minimum weight solution in the null space.
+    
+    // Build augmented matrix [A | b]
+    let mut aug: Vec<Vec<i64>> = vec![vec![0; num_buttons + 1]; num_counters];
+    for i in 0..num_counters {
+        for j in 0..num_buttons {
+            aug[i][j] = matrix[i][j];
+        }
+        aug[i][num_buttons] = target[i];
+    }
+    
+    // Gaussian elimination (over rationals, but we'll work with integers and track pivots)
+    let mut pivot_cols: Vec<usize> = Vec::new();
+    let mut pivot_rows: Vec<usize> = Vec::new();
+    let mut current_row = 0;
+    
+    for col in 0..num_buttons {
+        // Find a pivot in this column
+        let mut pivot = None;
+        for row in current_row..num_counters {
+            if aug[row][col] != 0 {
+                pivot = Some(row);
+                break;
+            }
+        }
+        
+        if let Some(pivot_row) = pivot {
+            // Swap rows
+            aug.swap(current_row, pivot_row);
+            pivot_cols.push(col);
+            pivot_rows.push(current_row);
+            
+            // Eliminate other rows
+            let pivot_val = aug[current_row][col];
+            for row in 0..num_counters {
+                if row != current_row && aug[row][col] != 0 {
+                    let factor = aug[row][col];
+                    for c in 0..=num_buttons {
+                        aug[row][c] = aug[row][c] * pivot_val - aug[current_row][c] * factor;
+                    }
+                }
+            }
+            
+            current_row += 1;
+        }
+    }
+    
+    let rank = pivot_cols.len();
+    
+    // Check for inconsistency
+    for row in rank..num_counters {
+        if aug[row][num_buttons] != 0 {
+            return None;
+        }
+    }
+    
+    // Free variables are columns not in pivot_cols
+    let pivot_set: std::collections::HashSet<usize> = pivot_cols.iter().copied().collect();
+    let free_vars: Vec<usize> = (0..num_buttons).filter(|c| !pivot_set.contains(c)).collect();
+    
+    // For each assignment of free variables, solve for pivot variables
+    // and check if solution is valid (non-negative integers)
+    
+    // Due to the elimination, we have for each pivot row:
+    // pivot_val * x[pivot_col] + sum(aug[row][j] * x[j] for j > pivot_col) = aug[row][num_buttons]
+    
+    // We need to search over free variable assignments
+    // This can be expensive, so we'll use bounds
+    
+    // Compute upper bounds for each free variable
+    let mut upper_bounds: Vec<i64> = vec![0; num_buttons];
+    for &free_var in &free_vars {
+        // A button can be pressed at most min(target) times over the counters
+        // it raises, since every press adds 1 to each of them
+        let max_presses: i64 = (0..num_counters)
+            .filter(|&i| matrix[i][free_var] > 0)
+            .map(|i| target[i])
+            .min()
+            .unwrap_or(0);
+        upper_bounds[free_var] = max_presses;
+    }
+    
+    // Also compute bounds from the equations
+    for (&pivot_col, &pivot_row) in pivot_cols.iter().zip(pivot_rows.iter()) {
+        let rhs = aug[pivot_row][num_buttons];
+        let pivot_val = aug[pivot_row][pivot_col];
+        // x[pivot_col] needs to be >= 0, so:
+        // rhs - sum(aug[row][j]*x[j]) >= 0 (adjusted for sign of pivot_val)
+        // This gives us constraints on the free variables
+        let max_val = if pivot_val > 0 { rhs / pivot_val } else { (-rhs) / (-pivot_val) };
+        upper_bounds[pivot_col] = upper_bounds[pivot_col].max(max_val);
+    }
+    
+    // Simple enumeration with pruning
+    find_min_solution(&aug, &pivot_cols, &pivot_rows, &free_vars, &upper_bounds, num_buttons)
+}
+
+fn find_min_solution(
+    aug: &[Vec<i64>],
+    pivot_cols: &[usize],
+    pivot_rows: &[usize],
+    free_vars: &[usize],
+    upper_bounds: &[i64],
+    num_buttons: usize,
+) -> Option<usize> {
+    let num_free = free_vars.len();
+    
+    let mut best = usize::MAX;
+    
+    // Use recursive search with pruning
+    fn search(
+        free_idx: usize,
+        free_vars: &[usize],
+        free_values: &mut Vec<i64>,
+        aug: &[Vec<i64>],
+        pivot_cols: &[usize],
+        pivot_rows: &[usize],
+        num_buttons: usize,
+        current_sum: i64,
+        best: &mut usize,
+        upper_bounds: &[i64],
+    ) {
+        if free_idx == free_vars.len() {
+            // Compute pivot variable values
+            let mut solution = vec![0i64; num_buttons];
+            for (i, &var) in free_vars.iter().enumerate() {
+                solution[var] = free_values[i];
+            }
+            
+            // Back-substitute
+            let mut valid = true;
+            for i in (0..pivot_cols.len()).rev() {
+                let pivot_col = pivot_cols[i];
+                let pivot_row = pivot_rows[i];
+                let pivot_val = aug[pivot_row][pivot_col];
+                
+                let mut rhs = aug[pivot_row][num_buttons];
+                for j in (pivot_col + 1)..num_buttons {
+                    rhs -= aug[pivot_row][j] * solution[j];
+                }
+                
+                if rhs % pivot_val != 0 {
+                    valid = false;
+                    break;
+                }
+                
+                solution[pivot_col] = rhs / pivot_val;
+                
+                if solution[pivot_col] < 0 {
+                    valid = false;
+                    break;
+                }
+            }
+            
+            if valid {
+                let total: i64 = solution.iter().sum();
+                if total >= 0 && (total as usize) < *best {
+                    *best = total as usize;
+                }
+            }
+            return;
+        }
+        
+        // Prune if current sum already exceeds best
+        if current_sum as usize >= *best {
+            return;
+        }
+        
+        // Try values for this free variable
+        for val in 0..=upper_bounds[free_vars[free_idx]] {
+            free_values[free_idx] = val;
+            search(
+                free_idx + 1,
+                free_vars,
+                free_values,
+                aug,
+                pivot_cols,
+                pivot_rows,
+                num_buttons,
+                current_sum + val,
+                best,
+                upper_bounds,
+            );
+        }
+    }
+    
+    let mut free_values = vec![0i64; num_free];
+    
+    search(
+        0,
+        free_vars,
+        &mut free_values,
+        aug,
+        pivot_cols,
+        pivot_rows,
+        num_buttons,
+        0,
+        &mut best,
+        upper_bounds,
+    );
+    
+    (best != usize::MAX).then_some(best)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Parsed = Vec<Machine>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_machines(input)
+    }
+
+    fn part1(machines: &Self::Parsed) -> Answer {
+        solve_part1(machines).map_or(Answer::Unsolved, Answer::from)
+    }
+
+    fn part2(machines: &Self::Parsed) -> Answer {
+        solve_part2(machines).map_or(Answer::Unsolved, Answer::from)
+    }
+}
+
+impl Generator for Day10 {
+    const KNOBS: &'static [Knob] = &[
+        Knob { name: "machines", default: 150, help: "number of machines" },
+        Knob { name: "lights", default: 10, help: "most indicator lights per machine" },
+        Knob { name: "spare", default: 2, help: "most buttons beyond one per light" },
+        Knob { name: "presses", default: 30, help: "most presses of each button behind the joltage" },
+    ];
+
+    /// Targets are built from random button presses, so every machine can
+    /// reach both its light pattern and its joltage requirements.
+    fn generate(rng: &mut Rng, knobs: &Knobs) -> String {
+        let mut input = String::new();
+        for _ in 0..knobs.size("machines").max(1) {
+            let lights = rng.range(1..=knobs.get("lights").max(1)) as usize;
+            let count = lights as u64 + rng.range(0..=knobs.get("spare"));
+            let buttons: Vec<Vec<usize>> = (0..count)
+                .map(|_| {
+                    let wired: Vec<usize> = (0..lights).filter(|_| rng.chance(40)).collect();
+                    if wired.is_empty() { vec![rng.index(lights)] } else { wired }
+                })
+                .collect();
+
+            let mut target = vec![false; lights];
+            let mut joltage = vec![0; lights];
+            for button in &buttons {
+                let toggled = rng.chance(50);
+                let presses = rng.range(0..=knobs.get("presses"));
+                for &light in button {
+                    target[light] ^= toggled;
+                    joltage[light] += presses;
+                }
+            }
+
+            let diagram: String = target.iter().map(|&on| if on { '#' } else { '.' }).collect();
+            input.push_str(&format!("[{}]", diagram));
+            for button in &buttons {
+                let wiring: Vec<String> = button.iter().map(usize::to_string).collect();
+                input.push_str(&format!(" ({})", wiring.join(",")));
+            }
+            let joltage: Vec<String> = joltage.iter().map(u64::to_string).collect();
+            input.push_str(&format!(" {{{}}}\n", joltage.join(",")));
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_1_part1() {
+        let line = "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}";
+        let machine = parse_machine(line, line).unwrap();
+        assert_eq!(find_min_presses_part1(&machine.target, &machine.buttons), Some(2));
+    }
+
+    #[test]
+    fn test_example_2_part1() {
+        let line = "[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}";
+        let machine = parse_machine(line, line).unwrap();
+        assert_eq!(find_min_presses_part1(&machine.target, &machine.buttons), Some(3));
+    }
+
+    #[test]
+    fn test_example_3_part1() {
+        let line = "[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}";
+        let machine = parse_machine(line, line).unwrap();
+        assert_eq!(find_min_presses_part1(&machine.target, &machine.buttons), Some(2));
+    }
+
+    #[test]
+    fn test_all_examples_part1() {
+        let input = "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
+[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}
+[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}";
+        assert_eq!(solve_part1(&parse_machines(input).unwrap()), Some(7));
+    }
+
+    #[test]
+    fn test_example_1_part2() {
+        let line = "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}";
+        let machine = parse_machine(line, line).unwrap();
+        assert_eq!(find_min_presses_part2(&machine.joltage, &machine.buttons), Some(10));
+    }
+
+    #[test]
+    fn test_example_2_part2() {
+        let line = "[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}";
+        let machine = parse_machine(line, line).unwrap();
+        assert_eq!(find_min_presses_part2(&machine.joltage, &machine.buttons), Some(12));
+    }
+
+    #[test]
+    fn test_example_3_part2() {
+        let line = "[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}";
+        let machine = parse_machine(line, line).unwrap();
+        assert_eq!(find_min_presses_part2(&machine.joltage, &machine.buttons), Some(11));
+    }
+
+    #[test]
+    fn test_all_examples_part2() {
+        let input = "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
+[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}
+[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}";
+        assert_eq!(solve_part2(&parse_machines(input).unwrap()), Some(33));
+    }
+
+    #[test]
+    fn test_part2_free_buttons_can_exceed_the_reduced_targets() {
+        // Pressing (0,1,2) three times and (1,3) once is best, more presses
+        // than any right-hand side left after elimination.
+        let line = "[....] (1,2) (2,3) (0,1) (0) (1,3) (0,1,2) {3,4,3,1}";
+        let machine = parse_machine(line, line).unwrap();
+        assert_eq!(find_min_presses_part2(&machine.joltage, &machine.buttons), Some(4));
+    }
+
+    #[test]
+    fn test_unreachable_machines_are_unsolved() {
+        for line in ["[.#] (0) {1}", "[##] (0) {1,1}", "[.#] (0,1) {1}"] {
+            let machine = parse_machine(line, line).unwrap();
+            assert_eq!(find_min_presses_part1(&machine.target, &machine.buttons), None);
+        }
+        // (1) lights the diagram, but no presses make the joltages 2 and 1.
+        let machines = parse_machines("[.#] (1) (0,1) {2,1}").unwrap();
+        assert_eq!(Day10::part1(&machines), Answer::Unsigned(1));
+        assert_eq!(Day10::part2(&machines), Answer::Unsolved);
+    }
+
+    #[test]
+    fn test_parse_machine_errors() {
+        let source = "[.##.] (3) (1,3) {3,5,4,7}\n[.#x] (0)\n";
+        let err = parse_machines(source).unwrap_err();
+        assert_eq!((err.line, err.column, err.snippet.as_str()), (2, 4, "x"));
+
+        let line = "[.##.] (3) (1,4) {3,5,4,7}";
+        let err = parse_machine(line, line).unwrap_err();
+        assert_eq!((err.column, err.expected.as_str()), (15, "a light index below 4"));
+
+        let line = "[.##.] (3) (1,3 {3,5,4,7}";
+        let err = parse_machine(line, line).unwrap_err();
+        assert_eq!((err.column, err.expected.as_str()), (26, "`)` closing the button wiring"));
+
+        let line = "(3) {3}";
+        let err = parse_machine(line, line).unwrap_err();
+        assert_eq!((err.column, err.snippet.as_str()), (1, "(3) {3}"));
+    }
+}
@@ -0,0 +1,213 @@
+//! Slow but obviously correct references for both parts, used to check the
+//! elimination solvers and the capped free-variable search on small random
+//! machines.
+
+use crate::{find_min_presses_part1, find_min_presses_part2, Machine};
+use aoc_core::oracle::{Check, Oracle};
+use std::collections::{HashSet, VecDeque};
+
+/// Fewest presses that light exactly the target pattern, by breadth-first
+/// search over light patterns.
+pub fn min_presses_for_lights(machine: &Machine) -> Option<usize> {
+    let start = vec![false; machine.target.len()];
+    shortest_presses(start, &machine.target, &machine.buttons, |lights, button| {
+        let mut lights = lights.clone();
+        for &light in button {
+            lights[light] = !lights[light];
+        }
+        Some(lights)
+    })
+}
+
+/// Fewest presses that raise every counter to exactly its joltage, by
+/// breadth-first search over counter values that never overshoot.
+pub fn min_presses_for_joltage(machine: &Machine) -> Option<usize> {
+    let start = vec![0; machine.joltage.len()];
+    shortest_presses(start, &machine.joltage, &machine.buttons, |counters, button| {
+        let mut counters = counters.clone();
+        for &counter in button {
+            counters[counter] += 1;
+        }
+        counters
+            .iter()
+            .zip(&machine.joltage)
+            .all(|(value, target)| value <= target)
+            .then_some(counters)
+    })
+}
+
+fn shortest_presses<S: Clone + Eq + std::hash::Hash>(
+    start: S,
+    target: &S,
+    buttons: &[Vec<usize>],
+    press: impl Fn(&S, &[usize]) -> Option<S>,
+) -> Option<usize> {
+    let mut seen = HashSet::from([start.clone()]);
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((state, presses)) = queue.pop_front() {
+        if state == *target {
+            return Some(presses);
+        }
+        for button in buttons {
+            if let Some(next) = press(&state, button) {
+                if seen.insert(next.clone()) {
+                    queue.push_back((next, presses + 1));
+                }
+            }
+        }
+    }
+    None
+}
+
+/// A machine described by the presses that reach its targets, so any
+/// shrunk variant is still solvable.
+#[derive(Debug, Clone)]
+pub struct Presses {
+    pub lights: usize,
+    pub buttons: Vec<Vec<usize>>,
+    /// Per button, whether it is pressed an odd number of times for the lights.
+    pub toggled: Vec<bool>,
+    /// Per button, how often it is pressed for the joltage.
+    pub presses: Vec<usize>,
+}
+
+impl Presses {
+    pub fn machine(&self) -> Machine {
+        let mut target = vec![false; self.lights];
+        let mut joltage = vec![0; self.lights];
+        for (i, button) in self.buttons.iter().enumerate() {
+            for &light in button {
+                target[light] ^= self.toggled[i];
+                joltage[light] += self.presses[i];
+            }
+        }
+        Machine {
+            target,
+            buttons: self.buttons.clone(),
+            joltage,
+        }
+    }
+
+    fn without_button(&self, i: usize) -> Presses {
+        let mut fewer = self.clone();
+        fewer.buttons.remove(i);
+        fewer.toggled.remove(i);
+        fewer.presses.remove(i);
+        fewer
+    }
+
+    fn without_light(&self, light: usize) -> Presses {
+        let mut fewer = self.clone();
+        fewer.lights -= 1;
+        for button in &mut fewer.buttons {
+            button.retain(|&l| l != light);
+            for l in button.iter_mut().filter(|l| **l > light) {
+                *l -= 1;
+            }
+        }
+        // Drop buttons left wired to nothing, as the generator never makes them.
+        for i in (0..fewer.buttons.len()).rev() {
+            if fewer.buttons[i].is_empty() {
+                fewer = fewer.without_button(i);
+            }
+        }
+        fewer
+    }
+}
+
+fn shrink(case: &Presses) -> Vec<Presses> {
+    let mut candidates: Vec<Presses> = (0..case.buttons.len()).map(|i| case.without_button(i)).collect();
+    if case.lights > 1 {
+        candidates.extend((0..case.lights).map(|light| case.without_light(light)));
+    }
+    for i in 0..case.buttons.len() {
+        if case.toggled[i] {
+            let mut untoggled = case.clone();
+            untoggled.toggled[i] = false;
+            candidates.push(untoggled);
+        }
+        if case.presses[i] > 0 {
+            let mut fewer = case.clone();
+            fewer.presses[i] -= 1;
+            candidates.push(fewer);
+        }
+        if case.buttons[i].len() > 1 {
+            for j in 0..case.buttons[i].len() {
+                let mut unwired = case.clone();
+                unwired.buttons[i].remove(j);
+                candidates.push(unwired);
+            }
+        }
+    }
+    candidates
+}
+
+fn render(case: &Presses) -> String {
+    let machine = case.machine();
+    let diagram: String = machine.target.iter().map(|&on| if on { '#' } else { '.' }).collect();
+    let mut line = format!("[{}]", diagram);
+    for button in &machine.buttons {
+        let wiring: Vec<String> = button.iter().map(usize::to_string).collect();
+        line.push_str(&format!(" ({})", wiring.join(",")));
+    }
+    let joltage: Vec<String> = machine.joltage.iter().map(usize::to_string).collect();
+    line + &format!(" {{{}}}", joltage.join(","))
+}
+
+pub fn oracles() -> Vec<Box<dyn Check>> {
+    vec![Box::new(Oracle {
+        name: "fewest presses",
+        generate: |rng| {
+            let lights = rng.range(1..=4) as usize;
+            let count = rng.range(1..=6) as usize;
+            let buttons: Vec<Vec<usize>> = (0..count)
+                .map(|_| {
+                    let wired: Vec<usize> = (0..lights).filter(|_| rng.chance(50)).collect();
+                    if wired.is_empty() { vec![rng.index(lights)] } else { wired }
+                })
+                .collect();
+            Presses {
+                lights,
+                toggled: (0..count).map(|_| rng.chance(50)).collect(),
+                presses: (0..count).map(|_| rng.range(0..=4) as usize).collect(),
+                buttons,
+            }
+        },
+        shrink,
+        render,
+        fast: |case| {
+            let machine = case.machine();
+            (
+                find_min_presses_part1(&machine.target, &machine.buttons),
+                find_min_presses_part2(&machine.joltage, &machine.buttons),
+            )
+        },
+        reference: |case| {
+            let machine = case.machine();
+            (min_presses_for_lights(&machine), min_presses_for_joltage(&machine))
+        },
+    })]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_machine;
+
+    #[test]
+    fn test_examples() {
+        let line = "[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}";
+        let machine = parse_machine(line, line).unwrap();
+        assert_eq!(min_presses_for_lights(&machine), Some(3));
+        assert_eq!(min_presses_for_joltage(&machine), Some(12));
+    }
+
+    #[test]
+    fn test_fast_matches_reference() {
+        for check in oracles() {
+            if let Err(mismatch) = check.run(0, 300) {
+                panic!("{}: {}", check.name(), mismatch);
+            }
+        }
+    }
+}
@@ -0,0 +1,200 @@
+use aoc_core::gen::{Generator, Knob, Knobs};
+use aoc_core::parse::{self, ParseError};
+use aoc_core::rng::Rng;
+use aoc_core::{Answer, Solution};
+use std::collections::{HashMap, HashSet};
+
+/// Each device mapped to the devices its outputs connect to.
+pub type Graph = HashMap<String, Vec<String>>;
+
+/// Counts all paths from the current node to "out" using DFS with memoization.
+/// Returns the number of distinct paths from `current` to "out".
+pub fn count_paths<'a>(
+    current: &'a str,
+    graph: &'a Graph,
+    memo: &mut HashMap<&'a str, u64>,
+) -> u64 {
+    // Base case: reached the destination
+    if current == "out" {
+        return 1;
+    }
+
+    // Check if we've already computed the paths from this node
+    if let Some(&count) = memo.get(current) {
+        return count;
+    }
+
+    // Get the outputs for the current device
+    let outputs = match graph.get(current) {
+        Some(outputs) => outputs,
+        None => return 0, // Dead end - no outputs defined for this device
+    };
+
+    // Sum up paths through all outputs
+    let total_paths: u64 = outputs
+        .iter()
+        .map(|next| count_paths(next, graph, memo))
+        .sum();
+
+    // Cache the result
+    memo.insert(current, total_paths);
+
+    total_paths
+}
+
+/// Counts paths from current node to "out" that visit both required nodes.
+/// State tracks which of the required nodes (dac, fft) have been visited:
+/// 0 = neither, 1 = dac only, 2 = fft only, 3 = both
+pub fn count_paths_with_requirements<'a>(
+    current: &'a str,
+    state: u8,
+    graph: &'a Graph,
+    memo: &mut HashMap<(&'a str, u8), u64>,
+) -> u64 {
+    // Update state based on current node
+    let new_state = if current == "dac" {
+        state | 1
+    } else if current == "fft" {
+        state | 2
+    } else {
+        state
+    };
+
+    // Base case: reached the destination
+    if current == "out" {
+        // Only count this path if we've visited both dac and fft (state == 3)
+        return if new_state == 3 { 1 } else { 0 };
+    }
+
+    // Check if we've already computed the paths from this node with this state
+    if let Some(&count) = memo.get(&(current, new_state)) {
+        return count;
+    }
+
+    // Get the outputs for the current device
+    let outputs = match graph.get(current) {
+        Some(outputs) => outputs,
+        None => return 0, // Dead end - no outputs defined for this device
+    };
+
+    // Sum up paths through all outputs
+    let total_paths: u64 = outputs
+        .iter()
+        .map(|next| count_paths_with_requirements(next, new_state, graph, memo))
+        .sum();
+
+    // Cache the result
+    memo.insert((current, new_state), total_paths);
+
+    total_paths
+}
+
+pub fn parse_graph(input: &str) -> Result<Graph, ParseError> {
+    // Parse the input into a graph (adjacency list)
+    let mut graph = Graph::new();
+
+    for line in parse::lines(input) {
+        // Parse "device: output1 output2 output3"
+        let Some(colon) = line.find(':') else {
+            return Err(ParseError::at(input, line, "a device like `aaa: bbb ccc`"));
+        };
+        let (device, outputs) = (&line[..colon], &line[colon + 1..]);
+        if let Some(i) = outputs.find(':') {
+            return Err(ParseError::at(input, &outputs[i..i + 1], "an output device name"));
+        }
+
+        let device = device.trim();
+        if device.is_empty() {
+            return Err(ParseError::at(input, &line[colon..colon + 1], "a device name"));
+        }
+        let outputs: Vec<String> = outputs.split_whitespace().map(str::to_string).collect();
+
+        graph.insert(device.to_string(), outputs);
+    }
+
+    Ok(graph)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Parsed = Graph;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_graph(input)
+    }
+
+    // Part 1: Count all paths from "you" to "out"
+    fn part1(graph: &Self::Parsed) -> Answer {
+        let mut memo: HashMap<&str, u64> = HashMap::new();
+        count_paths("you", graph, &mut memo).into()
+    }
+
+    // Part 2: Count paths from "svr" to "out" that visit both "dac" and "fft"
+    fn part2(graph: &Self::Parsed) -> Answer {
+        let mut memo: HashMap<(&str, u8), u64> = HashMap::new();
+        count_paths_with_requirements("svr", 0, graph, &mut memo).into()
+    }
+}
+
+impl Generator for Day11 {
+    const KNOBS: &'static [Knob] = &[
+        Knob { name: "devices", default: 600, help: "devices with outputs, besides `out`" },
+        Knob { name: "outputs", default: 3, help: "most outputs per device, the next device included" },
+    ];
+
+    /// Builds a random DAG: devices are laid out in a random order and only
+    /// connect to devices later in it, `svr` first and `out` last. Every
+    /// device feeds the next one, so all of them are reachable, and the other
+    /// outputs are picked uniformly from everything later, which keeps path
+    /// counts polynomial in the number of devices.
+    fn generate(rng: &mut Rng, knobs: &Knobs) -> String {
+        const SPECIAL: [&str; 5] = ["svr", "you", "dac", "fft", "out"];
+        let devices = knobs.size("devices").clamp(4, 26 * 26 * 26 - 1);
+
+        let mut names: Vec<String> = Vec::with_capacity(devices + 1);
+        let mut taken: HashSet<String> = SPECIAL.iter().map(|s| s.to_string()).collect();
+        while names.len() < devices - 4 {
+            let name: String = (0..3).map(|_| (b'a' + rng.below(26) as u8) as char).collect();
+            if taken.insert(name.clone()) {
+                names.push(name);
+            }
+        }
+        for special in ["you", "dac", "fft"] {
+            names.insert(rng.index(names.len() + 1), special.to_string());
+        }
+        names.insert(0, "svr".to_string());
+        names.push("out".to_string());
+
+        let mut input = String::new();
+        for (i, name) in names[..devices].iter().enumerate() {
+            let mut outputs: Vec<usize> = (1..rng.range(1..=knobs.get("outputs").max(1)))
+                .map(|_| rng.range(i as u64 + 1..=devices as u64) as usize)
+                .collect();
+            outputs.push(i + 1);
+            outputs.sort();
+            outputs.dedup();
+            let outputs: Vec<&str> = outputs.iter().map(|&j| names[j].as_str()).collect();
+            input.push_str(&format!("{}: {}\n", name, outputs.join(" ")));
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_graph() {
+        let graph = parse_graph("you: aaa bbb\naaa: out\nbbb: out\n").unwrap();
+        let mut memo = HashMap::new();
+        assert_eq!(count_paths("you", &graph, &mut memo), 2);
+
+        let err = parse_graph("you: aaa\naaa out\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.snippet.as_str()), (2, 1, "aaa out"));
+        let err = parse_graph("you: aaa: out\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 9));
+    }
+}
@@ -0,0 +1,409 @@
+use aoc_core::gen::{Generator, Knob, Knobs};
+use aoc_core::grid::{Grid, NEIGHBOURS_4};
+use aoc_core::parse::{self, ParseError};
+use aoc_core::rng::Rng;
+use aoc_core::{Answer, Detail, Part, Solution};
+use std::collections::HashSet;
+
+pub type Shape = Vec<(i32, i32)>;
+
+/// A region under a tree: its size and how many of each shape must fit in it.
+#[derive(Debug)]
+pub struct Region {
+    pub width: usize,
+    pub height: usize,
+    pub counts: Vec<usize>,
+}
+
+/// The present shapes followed by the regions to pack them into.
+#[derive(Debug)]
+pub struct Puzzle {
+    pub shapes: Vec<Shape>,
+    pub regions: Vec<Region>,
+}
+
+/// Parse a shape from lines of # and .
+pub fn parse_shape(lines: &[&str]) -> Shape {
+    let mut shape = Vec::new();
+    for (y, line) in lines.iter().enumerate() {
+        for (x, ch) in line.chars().enumerate() {
+            if ch == '#' {
+                shape.push((x as i32, y as i32));
+            }
+        }
+    }
+    // Normalize shape to start at (0, 0)
+    normalize_shape(&mut shape);
+    shape
+}
+
+/// Normalize a shape so its minimum x and y are 0
+fn normalize_shape(shape: &mut Shape) {
+    if shape.is_empty() {
+        return;
+    }
+    let min_x = shape.iter().map(|(x, _)| *x).min().unwrap();
+    let min_y = shape.iter().map(|(_, y)| *y).min().unwrap();
+    for (x, y) in shape.iter_mut() {
+        *x -= min_x;
+        *y -= min_y;
+    }
+    shape.sort();
+}
+
+/// Generate all rotations and reflections of a shape
+pub fn get_all_orientations(shape: &Shape) -> Vec<Shape> {
+    let mut orientations = HashSet::new();
+    let mut current = shape.clone();
+    
+    // 4 rotations
+    for _ in 0..4 {
+        let mut normalized = current.clone();
+        normalize_shape(&mut normalized);
+        orientations.insert(normalized);
+        
+        // Also add horizontal flip
+        let mut flipped: Shape = current.iter().map(|(x, y)| (-*x, *y)).collect();
+        normalize_shape(&mut flipped);
+        orientations.insert(flipped);
+        
+        // Rotate 90 degrees clockwise: (x, y) -> (y, -x)
+        current = current.iter().map(|(x, y)| (*y, -*x)).collect();
+    }
+    
+    orientations.into_iter().collect()
+}
+
+/// Check if a shape can be placed at position (px, py) on the grid
+fn can_place(grid: &Grid<bool>, shape: &Shape, px: i32, py: i32, width: usize, height: usize) -> bool {
+    for &(sx, sy) in shape {
+        let x = px + sx;
+        let y = py + sy;
+        if x < 0 || y < 0 || x >= width as i32 || y >= height as i32 {
+            return false;
+        }
+        if grid[(y as usize, x as usize)] {
+            return false;
+        }
+    }
+    true
+}
+
+/// Place a shape on the grid
+fn place_shape(grid: &mut Grid<bool>, shape: &Shape, px: i32, py: i32) {
+    for &(sx, sy) in shape {
+        let x = (px + sx) as usize;
+        let y = (py + sy) as usize;
+        grid[(y, x)] = true;
+    }
+}
+
+/// Remove a shape from the grid
+fn remove_shape(grid: &mut Grid<bool>, shape: &Shape, px: i32, py: i32) {
+    for &(sx, sy) in shape {
+        let x = (px + sx) as usize;
+        let y = (py + sy) as usize;
+        grid[(y, x)] = false;
+    }
+}
+
+/// Try to solve the puzzle using backtracking
+/// Simple approach: just try all positions for each piece
+fn solve(
+    grid: &mut Grid<bool>,
+    pieces: &mut Vec<Vec<Shape>>, // Each element is the list of orientations for that piece
+    width: usize,
+    height: usize,
+) -> bool {
+    if pieces.is_empty() {
+        return true; // All pieces placed
+    }
+    
+    // Take the first remaining piece
+    let orientations = pieces.remove(0);
+    
+    // Try each orientation
+    for orientation in &orientations {
+        // Calculate bounds for placement
+        let max_x = orientation.iter().map(|(x, _)| *x).max().unwrap();
+        let max_y = orientation.iter().map(|(_, y)| *y).max().unwrap();
+        
+        // Try all valid positions
+        for py in 0..=(height as i32 - max_y - 1) {
+            for px in 0..=(width as i32 - max_x - 1) {
+                if can_place(grid, orientation, px, py, width, height) {
+                    place_shape(grid, orientation, px, py);
+                    
+                    if solve(grid, pieces, width, height) {
+                        return true;
+                    }
+                    
+                    remove_shape(grid, orientation, px, py);
+                }
+            }
+        }
+    }
+    
+    // Put the piece back
+    pieces.insert(0, orientations);
+    false
+}
+
+/// Check if a region can fit all the required pieces
+pub fn can_fit_region(shapes: &[Shape], width: usize, height: usize, counts: &[usize]) -> bool {
+    // Build list of pieces to place (with all their orientations)
+    let mut pieces: Vec<Vec<Shape>> = Vec::new();
+    
+    for (shape_idx, &count) in counts.iter().enumerate() {
+        if shape_idx >= shapes.len() || count == 0 {
+            continue;
+        }
+        let orientations = get_all_orientations(&shapes[shape_idx]);
+        for _ in 0..count {
+            pieces.push(orientations.clone());
+        }
+    }
+    
+    if pieces.is_empty() {
+        return true;
+    }
+    
+    // Calculate total cells needed
+    let total_cells_needed: usize = counts
+        .iter()
+        .enumerate()
+        .map(|(i, &c)| {
+            if i < shapes.len() {
+                c * shapes[i].len()
+            } else {
+                0
+            }
+        })
+        .sum();
+    
+    if total_cells_needed > width * height {
+        return false;
+    }
+    
+    // Sort pieces by size (larger first) for better pruning
+    pieces.sort_by(|a, b| {
+        let size_a = a[0].len();
+        let size_b = b[0].len();
+        size_b.cmp(&size_a)
+    });
+    
+    let mut grid = Grid::new(height, width, false);
+    solve(&mut grid, &mut pieces, width, height)
+}
+
+/// Parses the numbered present shapes followed by one `WxH: counts` line
+/// per region.
+pub fn parse_puzzle(input: &str) -> Result<Puzzle, ParseError> {
+    let mut shapes: Vec<Shape> = Vec::new();
+    let mut regions: Vec<Region> = Vec::new();
+    
+    // Header of the shape being read, along with its rows so far
+    let mut current_shape: Option<(&str, Vec<&str>)> = None;
+    
+    for line in parse::lines(input) {
+        if let Some((label, counts)) = line.split_once(':') {
+            // A region line starts with dimensions like "12x5:"
+            if let Some((width, height)) = label.split_once('x') {
+                finish_shape(input, &mut current_shape, &mut shapes)?;
+                let width = parse::number(input, width, "a region width")?;
+                let height = parse::number(input, height, "a region height")?;
+                let mut region = Region { width, height, counts: Vec::new() };
+                for count in counts.split_whitespace() {
+                    if region.counts.len() == shapes.len() {
+                        let expected = format!("at most {} present counts", shapes.len());
+                        return Err(ParseError::at(input, count, expected));
+                    }
+                    region.counts.push(parse::number(input, count, "a present count")?);
+                }
+                regions.push(region);
+                continue;
+            }
+            
+            // Otherwise it is a shape header like "0:", numbered in order
+            if !regions.is_empty() {
+                return Err(ParseError::at(input, line, "a region like `12x5: 1 0 1 0 2 2`"));
+            }
+            finish_shape(input, &mut current_shape, &mut shapes)?;
+            let index: usize = parse::number(input, label, "a shape index")?;
+            if index != shapes.len() {
+                let expected = format!("shape index {}", shapes.len());
+                return Err(ParseError::at(input, label.trim(), expected));
+            }
+            if !counts.trim().is_empty() {
+                return Err(ParseError::at(input, counts.trim(), "a line break after the shape index"));
+            }
+            current_shape = Some((line, Vec::new()));
+            continue;
+        }
+        
+        match &mut current_shape {
+            Some((_, rows)) => {
+                if let Some(i) = line.find(|c| c != '#' && c != '.') {
+                    let c = line[i..].chars().next().unwrap();
+                    return Err(ParseError::at(input, &line[i..i + c.len_utf8()], "a shape cell `#` or `.`"));
+                }
+                rows.push(line);
+            }
+            None if regions.is_empty() => {
+                return Err(ParseError::at(input, line, "a shape header like `0:`"));
+            }
+            None => return Err(ParseError::at(input, line, "a region like `12x5: 1 0 1 0 2 2`")),
+        }
+    }
+    
+    // Don't forget the last shape
+    finish_shape(input, &mut current_shape, &mut shapes)?;
+    
+    Ok(Puzzle { shapes, regions })
+}
+
+/// Adds the shape being read, if any, to `shapes`.
+fn finish_shape<'a>(
+    input: &str,
+    current_shape: &mut Option<(&'a str, Vec<&'a str>)>,
+    shapes: &mut Vec<Shape>,
+) -> Result<(), ParseError> {
+    if let Some((header, rows)) = current_shape.take() {
+        if rows.is_empty() {
+            let end = &header[header.len()..];
+            return Err(ParseError::at(input, end, "rows of `#` and `.` below the shape header"));
+        }
+        if !rows.iter().any(|row| row.contains('#')) {
+            return Err(ParseError::at(input, header, "at least one `#` cell"));
+        }
+        shapes.push(parse_shape(&rows));
+    }
+    Ok(())
+}
+
+/// Counts how many regions can fit all their presents, calling `on_progress`
+/// with the number of regions checked so far after each one.
+pub fn count_fitting_regions(puzzle: &Puzzle, mut on_progress: impl FnMut(usize)) -> usize {
+    let mut count = 0;
+    for (i, region) in puzzle.regions.iter().enumerate() {
+        if can_fit_region(&puzzle.shapes, region.width, region.height, &region.counts) {
+            count += 1;
+        }
+        on_progress(i + 1);
+    }
+    count
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Parsed = Puzzle;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_puzzle(input)
+    }
+
+    fn part1(puzzle: &Self::Parsed) -> Answer {
+        count_fitting_regions(puzzle, |_| {}).into()
+    }
+
+    // The final day has a single puzzle.
+    fn part2(_: &Self::Parsed) -> Answer {
+        Answer::Unsolved
+    }
+
+    fn details(puzzle: &Self::Parsed, part: Part) -> Vec<(&'static str, Detail)> {
+        match part {
+            Part::One => vec![
+                ("shapes", Detail::Int(puzzle.shapes.len() as i64)),
+                ("regions", Detail::Int(puzzle.regions.len() as i64)),
+            ],
+            Part::Two => Vec::new(),
+        }
+    }
+}
+
+impl Generator for Day12 {
+    const KNOBS: &'static [Knob] = &[
+        Knob { name: "shapes", default: 6, help: "number of present shapes" },
+        Knob { name: "box", default: 3, help: "side of the square each shape is drawn in" },
+        Knob { name: "regions", default: 50, help: "number of regions" },
+        Knob { name: "side", default: 12, help: "longest region side" },
+        Knob { name: "fill", default: 90, help: "most of a region's area, in percent, the presents cover (at most 100)" },
+    ];
+
+    fn generate(rng: &mut Rng, knobs: &Knobs) -> String {
+        let side = knobs.size("box").max(1);
+        let mut input = String::new();
+        let mut sizes = Vec::new();
+
+        for index in 0..knobs.size("shapes").max(1) {
+            // Grow a connected shape covering more than half of its box.
+            let mut shape = Grid::new(side, side, false);
+            let cells = rng.range((side * side / 2 + 1) as u64..=(side * side) as u64) as usize;
+            let mut filled = vec![(rng.index(side), rng.index(side))];
+            shape[filled[0]] = true;
+            while filled.len() < cells {
+                let from = *rng.choose(&filled);
+                if let Some(next) = shape.step(from, *rng.choose(&NEIGHBOURS_4)) {
+                    if !shape[next] {
+                        shape[next] = true;
+                        filled.push(next);
+                    }
+                }
+            }
+            sizes.push(cells);
+            input.push_str(&format!("{}:\n{}\n", index, shape.render(|&c| if c { '#' } else { '.' })));
+        }
+
+        for _ in 0..knobs.size("regions").max(1) {
+            let width = rng.range(1..=knobs.get("side").max(1));
+            let height = rng.range(1..=knobs.get("side").max(1));
+            let mut budget = (width * height * rng.range(0..=knobs.get("fill").min(100)) / 100) as usize;
+            let mut counts = vec![0; sizes.len()];
+            loop {
+                let shape = rng.index(sizes.len());
+                if sizes[shape] > budget {
+                    break;
+                }
+                budget -= sizes[shape];
+                counts[shape] += 1;
+            }
+            let counts: Vec<String> = counts.iter().map(usize::to_string).collect();
+            input.push_str(&format!("{}x{}: {}\n", width, height, counts.join(" ")));
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_puzzle() {
+        let puzzle = parse_puzzle("0:\n##\n#.\n\n1:\n#\n\n3x2: 1 2\n4x4: 0 1\n").unwrap();
+        assert_eq!(puzzle.shapes, vec![vec![(0, 0), (0, 1), (1, 0)], vec![(0, 0)]]);
+        assert_eq!(puzzle.regions.len(), 2);
+        assert_eq!(puzzle.regions[0].counts, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_parse_puzzle_errors() {
+        let err = parse_puzzle("0:\n#?\n\n3x2: 1\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.snippet.as_str()), (2, 2, "?"));
+
+        let err = parse_puzzle("0:\n#\n\n3y2: 1\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.snippet.as_str()), (4, 1, "3y2"));
+
+        let err = parse_puzzle("0:\n#\n\n3x2: 1 1\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.expected.as_str()), (4, 8, "at most 1 present counts"));
+
+        let err = parse_puzzle("0:\n\n1:\n#\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 3));
+
+        let err = parse_puzzle("0:\n...\n...\n\n3x3: 1\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.expected.as_str()), (1, 1, "at least one `#` cell"));
+    }
+}
@@ -0,0 +1,221 @@
+use crate::{tally_invalid_ids, Radix, RepeatPolicy};
+use std::iter::FusedIterator;
+
+/// The invalid IDs of a range in ascending order, found one at a time
+/// without listing the rest.
+///
+/// Each step looks for the nearest repeat of every block length the policy
+/// allows, so it costs a few divisions per digit length however wide the
+/// range is. `nth` and `nth_back` jump straight to the k-th ID by binary
+/// search over the closed-form count.
+#[derive(Debug, Clone)]
+pub struct InvalidIds {
+    /// The part of the range not yet yielded from either end.
+    remaining: Option<(u64, u64)>,
+    policy: RepeatPolicy,
+    radix: Radix,
+}
+
+impl InvalidIds {
+    pub fn new(start: u64, end: u64, policy: RepeatPolicy, radix: Radix) -> InvalidIds {
+        InvalidIds {
+            remaining: (start <= end).then_some((start, end)),
+            policy,
+            radix,
+        }
+    }
+
+    /// Skips every ID below `x`, so the next one yielded is the first
+    /// invalid ID at or above it.
+    pub fn skip_to(&mut self, x: u64) {
+        if let Some((start, end)) = self.remaining {
+            self.remaining = (x <= end).then_some((start.max(x), end));
+        }
+    }
+
+    /// Block lengths whose repeats make a `len`-digit ID invalid.
+    fn blocks(&self, len: u32) -> impl Iterator<Item = u32> + '_ {
+        (1..=len / 2).filter(move |&block| len.is_multiple_of(block) && self.policy.allows((len / block) as usize))
+    }
+
+    /// The smallest invalid ID in `lo..=hi`.
+    fn first_in(&self, lo: u64, hi: u64) -> Option<u64> {
+        let radix = self.radix;
+        for len in radix.digit_count(lo)..=radix.digit_count(hi) {
+            let from = (lo as u128).max(radix.pow(len - 1));
+            let first = self
+                .blocks(len)
+                .filter_map(|block| {
+                    let multiplier = (radix.pow(len) - 1) / (radix.pow(block) - 1);
+                    let base = from.div_ceil(multiplier).max(radix.pow(block - 1));
+                    (base < radix.pow(block)).then_some(base * multiplier)
+                })
+                .min();
+            if let Some(first) = first {
+                return (first <= hi as u128).then_some(first as u64);
+            }
+        }
+        None
+    }
+
+    /// The largest invalid ID in `lo..=hi`.
+    fn last_in(&self, lo: u64, hi: u64) -> Option<u64> {
+        let radix = self.radix;
+        for len in (radix.digit_count(lo)..=radix.digit_count(hi)).rev() {
+            let to = (hi as u128).min(radix.pow(len) - 1);
+            let last = self
+                .blocks(len)
+                .filter_map(|block| {
+                    let multiplier = (radix.pow(len) - 1) / (radix.pow(block) - 1);
+                    let base = (to / multiplier).min(radix.pow(block) - 1);
+                    (base >= radix.pow(block - 1)).then_some(base * multiplier)
+                })
+                .max();
+            if let Some(last) = last {
+                return (last >= lo as u128).then_some(last as u64);
+            }
+        }
+        None
+    }
+
+    /// How many invalid IDs `lo..=hi` holds.
+    fn count_in(&self, lo: u64, hi: u64) -> u64 {
+        tally_invalid_ids(lo, hi, self.policy, self.radix).count
+    }
+
+    /// Takes `id` off the front of what remains.
+    fn yield_front(&mut self, id: u64) -> Option<u64> {
+        let (_, end) = self.remaining?;
+        self.remaining = (id < end).then(|| (id + 1, end));
+        Some(id)
+    }
+
+    /// Takes `id` off the back of what remains.
+    fn yield_back(&mut self, id: u64) -> Option<u64> {
+        let (start, _) = self.remaining?;
+        self.remaining = (id > start).then(|| (start, id - 1));
+        Some(id)
+    }
+}
+
+impl Iterator for InvalidIds {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        let (start, end) = self.remaining?;
+        match self.first_in(start, end) {
+            Some(id) => self.yield_front(id),
+            None => {
+                self.remaining = None;
+                None
+            }
+        }
+    }
+
+    fn nth(&mut self, n: usize) -> Option<u64> {
+        let (start, end) = self.remaining?;
+        if self.count_in(start, end) <= n as u64 {
+            self.remaining = None;
+            return None;
+        }
+        // The smallest `hi` with more than `n` invalid IDs in `start..=hi`.
+        let (mut lo, mut hi) = (start, end);
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if self.count_in(start, mid) > n as u64 {
+                hi = mid;
+            } else {
+                lo = mid + 1;
+            }
+        }
+        self.yield_front(lo)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.remaining.map_or(0, |(start, end)| self.count_in(start, end) as usize);
+        (len, Some(len))
+    }
+
+    fn last(mut self) -> Option<u64> {
+        self.next_back()
+    }
+}
+
+impl DoubleEndedIterator for InvalidIds {
+    fn next_back(&mut self) -> Option<u64> {
+        let (start, end) = self.remaining?;
+        match self.last_in(start, end) {
+            Some(id) => self.yield_back(id),
+            None => {
+                self.remaining = None;
+                None
+            }
+        }
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<u64> {
+        let (start, end) = self.remaining?;
+        if self.count_in(start, end) <= n as u64 {
+            self.remaining = None;
+            return None;
+        }
+        // The largest `lo` with more than `n` invalid IDs in `lo..=end`.
+        let (mut lo, mut hi) = (start, end);
+        while lo < hi {
+            let mid = hi - (hi - lo) / 2;
+            if self.count_in(mid, end) > n as u64 {
+                lo = mid;
+            } else {
+                hi = mid - 1;
+            }
+        }
+        self.yield_back(lo)
+    }
+}
+
+impl ExactSizeIterator for InvalidIds {}
+
+impl FusedIterator for InvalidIds {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ascending_and_descending() {
+        let ids = InvalidIds::new(95, 1012, RepeatPolicy::PART2, Radix::DECIMAL);
+        assert_eq!(ids.len(), 11);
+        assert_eq!(ids.clone().collect::<Vec<_>>(), vec![99, 111, 222, 333, 444, 555, 666, 777, 888, 999, 1010]);
+        assert_eq!(ids.rev().take(2).collect::<Vec<_>>(), vec![1010, 999]);
+        assert_eq!(InvalidIds::new(12, 10, RepeatPolicy::PART2, Radix::DECIMAL).next(), None);
+        assert_eq!(InvalidIds::new(1, 10, RepeatPolicy::PART2, Radix::DECIMAL).next(), None);
+    }
+
+    #[test]
+    fn test_nth_and_skip_to() {
+        let mut ids = InvalidIds::new(1, u64::MAX, RepeatPolicy::PART1, Radix::DECIMAL);
+        assert_eq!(ids.nth(9), Some(1010));
+        assert_eq!(ids.next(), Some(1111));
+        ids.skip_to(123_123);
+        assert_eq!(ids.next(), Some(123_123));
+        assert_eq!(ids.nth_back(0), Some(18_446_744_071_844_674_407));
+        assert_eq!(ids.next_back(), Some(18_446_744_061_844_674_406));
+
+        let mut ids = InvalidIds::new(10, 99, RepeatPolicy::PART2, Radix::DECIMAL);
+        assert_eq!(ids.nth(3), Some(44));
+        assert_eq!(ids.nth_back(3), Some(66));
+        assert_eq!(ids.len(), 1);
+        assert_eq!(ids.nth(1), None);
+        assert_eq!(ids.next(), None);
+    }
+
+    #[test]
+    fn test_meeting_in_the_middle() {
+        let mut ids = InvalidIds::new(11, 33, RepeatPolicy::PART2, Radix::DECIMAL);
+        assert_eq!((ids.next(), ids.next_back(), ids.next(), ids.next_back()), (Some(11), Some(33), Some(22), None));
+        let mut ids = InvalidIds::new(1, u64::MAX, RepeatPolicy::PART2, Radix::BINARY);
+        assert_eq!(ids.next_back(), Some(u64::MAX));
+        ids.skip_to(u64::MAX);
+        assert_eq!(ids.next(), None);
+    }
+}
@@ -0,0 +1,568 @@
+use aoc_core::gen::{Generator, Knob, Knobs};
+use aoc_core::parse::ParseError;
+use aoc_core::rng::Rng;
+use aoc_core::{Answer, Detail, Part, Solution};
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, Sub};
+use std::str::FromStr;
+
+pub mod invalid_ids;
+pub mod pattern;
+pub mod radix;
+pub mod reference;
+pub mod strict;
+pub mod wide;
+
+pub use aoc_core::BigDecimal;
+pub use invalid_ids::InvalidIds;
+pub use pattern::{decompositions, primitive, Decomposition};
+pub use radix::Radix;
+pub use strict::{parse_ranges_strict, Problem, RangeIssue};
+pub use wide::{merge_ranges, parse_ranges_wide, tally_in, tally_invalid_ids_wide, total_invalid_ids_wide, IdWidth, Int, WideTally};
+use wide::tally_of_length;
+
+/// Which repeat counts make an ID invalid. Whatever the policy, a block has
+/// to appear at least twice.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RepeatPolicy {
+    /// Two identical halves, the part 1 rule.
+    ExactlyTwice,
+    /// `k` or more copies; `AtLeast(2)` is the part 2 rule.
+    AtLeast(usize),
+    Exactly(usize),
+    AtMost(usize),
+}
+
+impl RepeatPolicy {
+    pub const PART1: RepeatPolicy = RepeatPolicy::ExactlyTwice;
+    pub const PART2: RepeatPolicy = RepeatPolicy::AtLeast(2);
+
+    /// Whether an ID made of `repeats` copies of one block is invalid.
+    pub fn allows(self, repeats: usize) -> bool {
+        repeats >= 2
+            && match self {
+                RepeatPolicy::ExactlyTwice => repeats == 2,
+                RepeatPolicy::AtLeast(k) => repeats >= k,
+                RepeatPolicy::Exactly(k) => repeats == k,
+                RepeatPolicy::AtMost(k) => repeats <= k,
+            }
+    }
+}
+
+impl fmt::Display for RepeatPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RepeatPolicy::ExactlyTwice => write!(f, "twice"),
+            RepeatPolicy::AtLeast(k) => write!(f, "at-least:{}", k),
+            RepeatPolicy::Exactly(k) => write!(f, "exactly:{}", k),
+            RepeatPolicy::AtMost(k) => write!(f, "at-most:{}", k),
+        }
+    }
+}
+
+impl FromStr for RepeatPolicy {
+    type Err = String;
+
+    /// Parses `twice`, `at-least:K`, `exactly:K` or `at-most:K`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let expected = || format!("invalid repeat policy '{}', expected twice, at-least:K, exactly:K or at-most:K", s);
+        if s == "twice" {
+            return Ok(RepeatPolicy::ExactlyTwice);
+        }
+        let (name, k) = s.split_once(':').ok_or_else(expected)?;
+        let k: usize = k.parse().map_err(|_| expected())?;
+        match name {
+            "at-least" => Ok(RepeatPolicy::AtLeast(k)),
+            "exactly" => Ok(RepeatPolicy::Exactly(k)),
+            "at-most" => Ok(RepeatPolicy::AtMost(k)),
+            _ => Err(expected()),
+        }
+    }
+}
+
+pub fn is_repeated_sequence(n: u64, policy: RepeatPolicy, radix: Radix) -> bool {
+    let digits = radix.digits(n);
+    let len = digits.len();
+    
+    // Try all possible base sequence lengths (1 to len/2)
+    // The sequence must repeat at least twice, so base length <= len/2
+    for base_len in 1..=len / 2 {
+        // The total length must be divisible by the base length, in a
+        // number of copies the policy counts
+        if !len.is_multiple_of(base_len) || !policy.allows(len / base_len) {
+            continue;
+        }
+        
+        let base = &digits[..base_len];
+        
+        // Base sequence cannot start with a zero digit
+        if base[0] == 0 {
+            continue;
+        }
+        
+        // Check if the entire number is made of this base repeated
+        if digits.chunks(base_len).all(|block| block == base) {
+            return true;
+        }
+    }
+    
+    false
+}
+
+/// Every invalid ID in `start..=end`, in ascending order.
+pub fn find_invalid_ids_in_range(start: u64, end: u64, policy: RepeatPolicy, radix: Radix) -> Vec<u64> {
+    InvalidIds::new(start, end, policy, radix).collect()
+}
+
+/// How many invalid IDs a range holds and what they add up to.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Tally {
+    pub count: u64,
+    pub sum: u128,
+}
+
+impl Add for Tally {
+    type Output = Tally;
+
+    fn add(self, other: Tally) -> Tally {
+        Tally {
+            count: self.count + other.count,
+            sum: self.sum + other.sum,
+        }
+    }
+}
+
+impl Sub for Tally {
+    type Output = Tally;
+
+    fn sub(self, other: Tally) -> Tally {
+        Tally {
+            count: self.count - other.count,
+            sum: self.sum - other.sum,
+        }
+    }
+}
+
+impl From<WideTally<u128>> for Tally {
+    fn from(tally: WideTally<u128>) -> Tally {
+        Tally {
+            count: tally.count as u64,
+            sum: tally.sum,
+        }
+    }
+}
+
+impl Sum for Tally {
+    fn sum<I: Iterator<Item = Tally>>(iter: I) -> Tally {
+        iter.fold(Tally::default(), Add::add)
+    }
+}
+
+/// Counts and sums the invalid IDs in `start..=end` without listing them,
+/// exactly for any `u64` bounds.
+///
+/// In base `b`, an `n`-digit ID made of an `L`-digit block repeated `n / L`
+/// times is `block * (b^n - 1) / (b^L - 1)`, so the blocks landing in the range
+/// form an interval and their IDs an arithmetic series. IDs such as 1111
+/// are built from several block lengths (1 and 2), so they are counted by
+/// their shortest block `p` instead: Möbius inversion over the divisors of
+/// `p` gives `primitive(p) = Σ μ(p / L) · repeats(L)` over `L | p`, and an
+/// ID is invalid when the policy allows some number of copies of a block
+/// that is itself made of copies of `p`.
+pub fn tally_invalid_ids(start: u64, end: u64, policy: RepeatPolicy, radix: Radix) -> Tally {
+    tally_in(&start, &end, policy, radix).into()
+}
+
+/// The prefix function `F(n)`: count and sum of the invalid IDs up to `n`,
+/// with the totals of every shorter digit length worked out up front.
+///
+/// A range is then `F(end) - F(start - 1)`, which costs one pass of the
+/// inclusion-exclusion over the divisors of a single length.
+#[derive(Debug, Clone)]
+pub struct PrefixTally {
+    policy: RepeatPolicy,
+    radix: Radix,
+    /// Entry `len` covers every ID with fewer than `len` digits.
+    shorter: Vec<Tally>,
+}
+
+impl PrefixTally {
+    pub fn new(policy: RepeatPolicy, radix: Radix) -> PrefixTally {
+        let mut shorter = vec![Tally::default(); 2];
+        for len in 1..=radix.digit_count(u64::MAX) {
+            let hi = (radix.pow(len) - 1).min(u64::MAX as u128);
+            let below = shorter[len as usize];
+            shorter.push(below + tally_of_length(&radix.pow(len - 1), &hi, len, policy, radix).into());
+        }
+        PrefixTally { policy, radix, shorter }
+    }
+
+    /// Count and sum of the invalid IDs in `0..=n`.
+    pub fn up_to(&self, n: u64) -> Tally {
+        if n == 0 {
+            return Tally::default();
+        }
+        let len = self.radix.digit_count(n);
+        let lo = self.radix.pow(len - 1);
+        self.shorter[len as usize] + tally_of_length(&lo, &(n as u128), len, self.policy, self.radix).into()
+    }
+
+    /// Count and sum of the invalid IDs in `start..=end`.
+    pub fn range(&self, start: u64, end: u64) -> Tally {
+        match start {
+            _ if start > end => Tally::default(),
+            0 => self.up_to(end),
+            _ => self.up_to(end) - self.up_to(start - 1),
+        }
+    }
+}
+
+/// The Möbius function: 0 if `n` has a squared prime factor, otherwise -1 or
+/// 1 for an odd or even number of prime factors.
+pub(crate) fn mobius(mut n: u32) -> i32 {
+    let mut sign = 1;
+    let mut p = 2;
+    while p * p <= n {
+        if n.is_multiple_of(p) {
+            n /= p;
+            if n.is_multiple_of(p) {
+                return 0;
+            }
+            sign = -sign;
+        }
+        p += 1;
+    }
+    if n > 1 {
+        sign = -sign;
+    }
+    sign
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<(u64, u64)>, ParseError> {
+    parse_ranges(input, Radix::DECIMAL)
+}
+
+/// Parses comma-separated ranges whose bounds are written in `radix`, with
+/// or without its prefix, such as `0x1f-0xff` in hex.
+pub fn parse_ranges(input: &str, radix: Radix) -> Result<Vec<(u64, u64)>, ParseError> {
+    parse_ranges_wide(input, radix)
+}
+
+/// Invalid IDs over a list of ranges, both with every ID counted once and
+/// with every range counted on its own.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Totals {
+    pub union: Tally,
+    pub per_range: Tally,
+}
+
+impl Totals {
+    /// The extra hits on IDs that more than one range holds.
+    pub fn duplicates(&self) -> Tally {
+        self.per_range - self.union
+    }
+}
+
+pub fn total_invalid_ids(ranges: &[(u64, u64)], policy: RepeatPolicy, radix: Radix) -> Totals {
+    let tally = |ranges: &[(u64, u64)]| -> Tally {
+        ranges
+            .iter()
+            .map(|&(start, end)| tally_invalid_ids(start, end, policy, radix))
+            .sum()
+    };
+    Totals {
+        union: tally(&merge_ranges(ranges)),
+        per_range: tally(ranges),
+    }
+}
+
+/// Sums every ID in the union of the ranges that `policy` makes invalid.
+pub fn sum_invalid_ids(ranges: &[(u64, u64)], policy: RepeatPolicy, radix: Radix) -> u128 {
+    merge_ranges(ranges)
+        .iter()
+        .map(|&(start, end)| tally_invalid_ids(start, end, policy, radix).sum)
+        .sum()
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    type Parsed = Vec<(u64, u64)>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(ranges: &Self::Parsed) -> Answer {
+        sum_invalid_ids(ranges, RepeatPolicy::PART1, Radix::DECIMAL).into()
+    }
+
+    fn part2(ranges: &Self::Parsed) -> Answer {
+        sum_invalid_ids(ranges, RepeatPolicy::PART2, Radix::DECIMAL).into()
+    }
+
+    fn details(ranges: &Self::Parsed, part: Part) -> Vec<(&'static str, Detail)> {
+        let policy = match part {
+            Part::One => RepeatPolicy::PART1,
+            Part::Two => RepeatPolicy::PART2,
+        };
+        let totals = total_invalid_ids(ranges, policy, Radix::DECIMAL);
+        vec![
+            ("ranges", Detail::Int(ranges.len() as i64)),
+            ("merged_ranges", Detail::Int(merge_ranges(ranges).len() as i64)),
+            ("duplicate_hits", Detail::Int(totals.duplicates().count as i64)),
+        ]
+    }
+}
+
+impl Generator for Day2 {
+    const KNOBS: &'static [Knob] = &[
+        Knob { name: "ranges", default: 30, help: "number of ID ranges" },
+        Knob { name: "digits", default: 10, help: "most digits in a range start (at most 19)" },
+        Knob { name: "span", default: 1_000_000, help: "largest gap between a range's start and end" },
+    ];
+
+    fn generate(rng: &mut Rng, knobs: &Knobs) -> String {
+        let digits = knobs.get("digits").clamp(1, 19) as u32;
+        let ranges: Vec<String> = (0..knobs.size("ranges").max(1))
+            .map(|_| {
+                let len = rng.range(1..=digits as u64) as u32;
+                let start = rng.range(10u64.pow(len - 1)..=10u64.pow(len) - 1);
+                let end = start.saturating_add(rng.range(0..=knobs.get("span")));
+                format!("{}-{}", start, end)
+            })
+            .collect();
+        ranges.join(",") + "\n"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_repeated_sequence() {
+        // Part 2: sequences repeated at least twice
+        assert!(is_repeated_sequence(55, RepeatPolicy::PART2, Radix::DECIMAL));
+        assert!(is_repeated_sequence(6464, RepeatPolicy::PART2, Radix::DECIMAL));
+        assert!(is_repeated_sequence(123123, RepeatPolicy::PART2, Radix::DECIMAL));
+        assert!(is_repeated_sequence(11, RepeatPolicy::PART2, Radix::DECIMAL));
+        assert!(is_repeated_sequence(22, RepeatPolicy::PART2, Radix::DECIMAL));
+        assert!(is_repeated_sequence(99, RepeatPolicy::PART2, Radix::DECIMAL));
+        assert!(is_repeated_sequence(1010, RepeatPolicy::PART2, Radix::DECIMAL));
+        
+        // New cases for part 2
+        assert!(is_repeated_sequence(111, RepeatPolicy::PART2, Radix::DECIMAL));      // 1 repeated 3 times
+        assert!(is_repeated_sequence(999, RepeatPolicy::PART2, Radix::DECIMAL));      // 9 repeated 3 times
+        assert!(is_repeated_sequence(1111111, RepeatPolicy::PART2, Radix::DECIMAL));  // 1 repeated 7 times
+        assert!(is_repeated_sequence(123123123, RepeatPolicy::PART2, Radix::DECIMAL)); // 123 repeated 3 times
+        assert!(is_repeated_sequence(1212121212, RepeatPolicy::PART2, Radix::DECIMAL)); // 12 repeated 5 times
+        assert!(is_repeated_sequence(565656, RepeatPolicy::PART2, Radix::DECIMAL));   // 56 repeated 3 times
+        assert!(is_repeated_sequence(824824824, RepeatPolicy::PART2, Radix::DECIMAL)); // 824 repeated 3 times
+        
+        assert!(!is_repeated_sequence(101, RepeatPolicy::PART2, Radix::DECIMAL));
+        assert!(!is_repeated_sequence(12, RepeatPolicy::PART2, Radix::DECIMAL));
+        assert!(!is_repeated_sequence(123, RepeatPolicy::PART2, Radix::DECIMAL));
+        assert!(!is_repeated_sequence(1234, RepeatPolicy::PART2, Radix::DECIMAL));
+    }
+
+    #[test]
+    fn test_example_part2() {
+        let input = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,\
+                     446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
+        let ranges = parse_input(input).unwrap();
+        
+        let mut total_sum: u64 = 0;
+        for (start, end) in ranges {
+            let invalid_ids = find_invalid_ids_in_range(start, end, RepeatPolicy::PART2, Radix::DECIMAL);
+            for id in &invalid_ids {
+                total_sum += id;
+            }
+        }
+        
+        assert_eq!(total_sum, 4174379265);
+    }
+
+    #[test]
+    fn test_solution_example() {
+        let input = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,\
+                     446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
+        let (part1, part2) = aoc_core::solution::solve::<Day2>(input).unwrap();
+        assert_eq!(part1, Answer::Unsigned(1227775554));
+        assert_eq!(part2, Answer::Unsigned(4174379265));
+    }
+
+    #[test]
+    fn test_repeat_policies() {
+        use RepeatPolicy::*;
+        assert!(is_repeated_sequence(123123, ExactlyTwice, Radix::DECIMAL));
+        assert!(!is_repeated_sequence(111, ExactlyTwice, Radix::DECIMAL));
+        // 111111 is 111 twice as well as 11 three times and 1 six times.
+        assert!(is_repeated_sequence(111111, Exactly(3), Radix::DECIMAL));
+        assert!(!is_repeated_sequence(121212, Exactly(2), Radix::DECIMAL));
+        assert!(is_repeated_sequence(121212, AtMost(3), Radix::DECIMAL));
+        assert!(!is_repeated_sequence(1111111, AtMost(6), Radix::DECIMAL));
+        assert!(!is_repeated_sequence(11, AtLeast(3), Radix::DECIMAL));
+
+        let ids = find_invalid_ids_in_range(95, 1012, ExactlyTwice, Radix::DECIMAL);
+        assert_eq!(ids, vec![99, 1010]);
+        assert_eq!(tally_invalid_ids(95, 1012, ExactlyTwice, Radix::DECIMAL).sum, 1109);
+        assert_eq!(tally_invalid_ids(1, 999_999, Exactly(3), Radix::DECIMAL), Tally { count: 9 + 90, sum: 4995 + 10101 * 4905 });
+    }
+
+    #[test]
+    fn test_repeat_policy_round_trips() {
+        for policy in [RepeatPolicy::ExactlyTwice, RepeatPolicy::AtLeast(3), RepeatPolicy::Exactly(4), RepeatPolicy::AtMost(2)] {
+            assert_eq!(policy.to_string().parse(), Ok(policy));
+        }
+        assert!("thrice".parse::<RepeatPolicy>().is_err());
+        assert!("at-most:x".parse::<RepeatPolicy>().is_err());
+    }
+
+    #[test]
+    fn test_individual_ranges_part2() {
+        // 11-22 still has two invalid IDs, 11 and 22
+        let ids = find_invalid_ids_in_range(11, 22, RepeatPolicy::PART2, Radix::DECIMAL);
+        assert!(ids.contains(&11));
+        assert!(ids.contains(&22));
+        assert_eq!(ids.len(), 2);
+
+        // 95-115 now has two invalid IDs, 99 and 111
+        let ids = find_invalid_ids_in_range(95, 115, RepeatPolicy::PART2, Radix::DECIMAL);
+        assert!(ids.contains(&99));
+        assert!(ids.contains(&111));
+        assert_eq!(ids.len(), 2);
+
+        // 998-1012 now has two invalid IDs, 999 and 1010
+        let ids = find_invalid_ids_in_range(998, 1012, RepeatPolicy::PART2, Radix::DECIMAL);
+        assert!(ids.contains(&999));
+        assert!(ids.contains(&1010));
+        assert_eq!(ids.len(), 2);
+
+        // 1188511880-1188511890 still has one invalid ID, 1188511885
+        let ids = find_invalid_ids_in_range(1188511880, 1188511890, RepeatPolicy::PART2, Radix::DECIMAL);
+        assert!(ids.contains(&1188511885));
+        assert_eq!(ids.len(), 1);
+
+        // 222220-222224 still has one invalid ID, 222222
+        let ids = find_invalid_ids_in_range(222220, 222224, RepeatPolicy::PART2, Radix::DECIMAL);
+        assert!(ids.contains(&222222));
+        assert_eq!(ids.len(), 1);
+
+        // 1698522-1698528 still contains no invalid IDs
+        let ids = find_invalid_ids_in_range(1698522, 1698528, RepeatPolicy::PART2, Radix::DECIMAL);
+        assert_eq!(ids.len(), 0);
+
+        // 446443-446449 still has one invalid ID, 446446
+        let ids = find_invalid_ids_in_range(446443, 446449, RepeatPolicy::PART2, Radix::DECIMAL);
+        assert!(ids.contains(&446446));
+        assert_eq!(ids.len(), 1);
+
+        // 38593856-38593862 still has one invalid ID, 38593859
+        let ids = find_invalid_ids_in_range(38593856, 38593862, RepeatPolicy::PART2, Radix::DECIMAL);
+        assert!(ids.contains(&38593859));
+        assert_eq!(ids.len(), 1);
+
+        // 565653-565659 now has one invalid ID, 565656
+        let ids = find_invalid_ids_in_range(565653, 565659, RepeatPolicy::PART2, Radix::DECIMAL);
+        assert!(ids.contains(&565656));
+        assert_eq!(ids.len(), 1);
+
+        // 824824821-824824827 now has one invalid ID, 824824824
+        let ids = find_invalid_ids_in_range(824824821, 824824827, RepeatPolicy::PART2, Radix::DECIMAL);
+        assert!(ids.contains(&824824824));
+        assert_eq!(ids.len(), 1);
+
+        // 2121212118-2121212124 now has one invalid ID, 2121212121
+        let ids = find_invalid_ids_in_range(2121212118, 2121212124, RepeatPolicy::PART2, Radix::DECIMAL);
+        assert!(ids.contains(&2121212121));
+        assert_eq!(ids.len(), 1);
+    }
+
+    #[test]
+    fn test_tally_matches_enumeration() {
+        for (start, end) in parse_input("11-22,95-115,998-1012,1-100000,222220-222224,1698522-1698528").unwrap() {
+            let ids = find_invalid_ids_in_range(start, end, RepeatPolicy::PART2, Radix::DECIMAL);
+            let tally = tally_invalid_ids(start, end, RepeatPolicy::PART2, Radix::DECIMAL);
+            assert_eq!(tally.count, ids.len() as u64);
+            assert_eq!(tally.sum, ids.iter().map(|&id| id as u128).sum::<u128>());
+        }
+        assert_eq!(tally_invalid_ids(22, 11, RepeatPolicy::PART2, Radix::DECIMAL), Tally::default());
+    }
+
+    #[test]
+    fn test_tally_of_wide_ranges() {
+        // 1111 is both 1 and 11 repeated and must be counted once.
+        assert_eq!(tally_invalid_ids(1000, 9999, RepeatPolicy::PART2, Radix::DECIMAL), Tally { count: 90, sum: 495_405 });
+        // Splitting a 20-digit range must not lose or double any ID.
+        let split = 15_151_515_151_515_151_515;
+        let top = tally_invalid_ids(10u64.pow(19), u64::MAX, RepeatPolicy::PART2, Radix::DECIMAL);
+        let halves = tally_invalid_ids(10u64.pow(19), split, RepeatPolicy::PART2, Radix::DECIMAL)
+            + tally_invalid_ids(split + 1, u64::MAX, RepeatPolicy::PART2, Radix::DECIMAL);
+        assert_eq!(top, halves);
+        assert_eq!(tally_invalid_ids(split, split, RepeatPolicy::PART2, Radix::DECIMAL).count, 1);
+        let everything = tally_invalid_ids(0, u64::MAX, RepeatPolicy::PART2, Radix::DECIMAL);
+        assert_eq!(everything, tally_invalid_ids(0, 10u64.pow(19) - 1, RepeatPolicy::PART2, Radix::DECIMAL) + top);
+        assert!(everything.sum > u64::MAX as u128);
+    }
+
+    #[test]
+    fn test_prefix_tally_matches_direct_tally() {
+        for radix in [Radix::BINARY, Radix::DECIMAL, Radix::HEX] {
+            let prefix = PrefixTally::new(RepeatPolicy::PART2, radix);
+            assert_eq!(prefix.up_to(u64::MAX), tally_invalid_ids(0, u64::MAX, RepeatPolicy::PART2, radix));
+            for (start, end) in [(0, 0), (0, 99), (11, 22), (95, 1012), (1_000_000, 123_456_789), (1 << 40, u64::MAX - 7)] {
+                assert_eq!(prefix.range(start, end), tally_invalid_ids(start, end, RepeatPolicy::PART2, radix));
+            }
+            assert_eq!(prefix.range(22, 11), Tally::default());
+        }
+        let prefix = PrefixTally::new(RepeatPolicy::PART1, Radix::DECIMAL);
+        assert_eq!(prefix.up_to(100), Tally { count: 9, sum: 495 });
+    }
+
+    #[test]
+    fn test_overlapping_ranges_count_each_id_once() {
+        let ranges = [(95, 115), (100, 1012), (11, 22), (23, 30), (50, 40)];
+        assert_eq!(merge_ranges(&ranges), vec![(11, 30), (95, 1012)]);
+        assert_eq!(merge_ranges(&[(5, u64::MAX), (u64::MAX, u64::MAX)]), vec![(5, u64::MAX)]);
+
+        // 111 lies in both of the first two ranges.
+        let totals = total_invalid_ids(&ranges, RepeatPolicy::PART2, Radix::DECIMAL);
+        assert_eq!(totals.duplicates(), Tally { count: 1, sum: 111 });
+        assert_eq!(totals.union.sum, 11 + 22 + 99 + 111 + 222 + 333 + 444 + 555 + 666 + 777 + 888 + 999 + 1010);
+        assert_eq!(sum_invalid_ids(&ranges, RepeatPolicy::PART2, Radix::DECIMAL), totals.union.sum);
+    }
+
+    #[test]
+    fn test_other_radixes() {
+        let ranges = parse_ranges("0x1f-0xff, 100-0X1000", Radix::HEX).unwrap();
+        assert_eq!(ranges, vec![(0x1f, 0xff), (0x100, 0x1000)]);
+        let ids = find_invalid_ids_in_range(0x1f, 0xff, RepeatPolicy::PART2, Radix::HEX);
+        // 0x22 through 0xff, every two-digit hex number with equal digits.
+        assert_eq!(ids, (2..=15).map(|d| d * 0x11).collect::<Vec<u64>>());
+        assert!(is_repeated_sequence(0b101101, RepeatPolicy::PART1, Radix::BINARY));
+        assert!(!is_repeated_sequence(0b101101, RepeatPolicy::PART1, Radix::DECIMAL));
+
+        // In binary, every 64-bit ID is in range and the tally must stay exact.
+        let binary = tally_invalid_ids(0, u64::MAX, RepeatPolicy::PART2, Radix::BINARY);
+        let split = (1 << 63) + (1 << 40);
+        let halves = tally_invalid_ids(0, split, RepeatPolicy::PART2, Radix::BINARY)
+            + tally_invalid_ids(split + 1, u64::MAX, RepeatPolicy::PART2, Radix::BINARY);
+        assert_eq!(binary, halves);
+        assert!(binary.sum > u64::MAX as u128);
+    }
+
+    #[test]
+    fn test_parse_input_rejects_malformed_ranges() {
+        let err = parse_input("11-22,95115,998-1012").unwrap_err();
+        assert_eq!((err.line, err.column, err.snippet.as_str()), (1, 7, "95115"));
+
+        let err = parse_input("11-22,\n95-x").unwrap_err();
+        assert_eq!((err.line, err.column, err.snippet.as_str()), (2, 4, "x"));
+
+        let err = parse_ranges("0x1f-0xfg", Radix::HEX).unwrap_err();
+        assert_eq!((err.column, err.expected.as_str()), (6, "a range end in base 16"));
+    }
+}
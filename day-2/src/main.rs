@@ -1,228 +1,281 @@
-use std::fs;
-use std::collections::HashSet;
-
-fn is_repeated_sequence(n: u64) -> bool {
-    let s = n.to_string();
-    let len = s.len();
-    
-    // Try all possible base sequence lengths (1 to len/2)
-    // The sequence must repeat at least twice, so base length <= len/2
-    for base_len in 1..=len / 2 {
-        // The total length must be divisible by the base length
-        if len % base_len != 0 {
-            continue;
-        }
-        
-        let base = &s[..base_len];
-        
-        // Base sequence cannot start with '0'
-        if base.starts_with('0') {
-            continue;
+use aoc_core::cli::{ArgError, Args};
+use aoc_core::parse::ParseError;
+use day_2::{
+    decompositions, merge_ranges, parse_ranges, parse_ranges_strict, parse_ranges_wide, total_invalid_ids,
+    total_invalid_ids_wide, BigDecimal, IdWidth, InvalidIds, PrefixTally, Radix, RepeatPolicy,
+};
+use std::io::{self, BufRead, BufWriter, Write};
+use std::process::{self, ExitCode};
+use std::str::FromStr;
+
+const USAGE: &str = "\
+Usage: day-2 [--repeats POLICY] [--radix N] [--per-range] [--table FORMAT] [--strict] [INPUT]
+       day-2 --width WIDTH [--repeats POLICY] [--radix N] [INPUT]
+       day-2 --batch [--repeats POLICY] [--radix N]
+
+Sums the invalid IDs in INPUT (input.txt by default). Without --repeats both
+puzzle parts are answered; POLICY is one of twice, at-least:K, exactly:K or
+at-most:K copies of a block. With --radix, IDs are written in base N (2 to
+36), and range bounds such as 0x1f-0xff may carry the base's prefix.
+
+Overlapping ranges are merged first so that every ID counts once;
+--per-range counts an ID once for every range holding it instead.
+
+--strict also accepts whitespace and newlines between ranges, but rejects
+reversed, duplicate and empty ranges and bounds over 64 bits, listing every
+problem with its byte offset.
+
+--table lists every invalid ID of every range, as text or csv, instead of
+the sums: its shortest repeated block, that block's length and repeat
+count, and the longer blocks that also repeat into it. The repeat policy
+defaults to at-least:2.
+
+--width reads IDs as wide as WIDTH allows, one of u64, u128 or big for any
+size, and sums them without overflow, so 40-digit ranges work too.
+
+--batch reads ranges from stdin, a line at a time, and answers each as it
+arrives with a line holding the range, then the count and sum of its
+invalid IDs for POLICY, or for both parts without --repeats.";
+
+/// How --table prints the invalid IDs of every range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TableFormat {
+    Text,
+    Csv,
+}
+
+impl FromStr for TableFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(TableFormat::Text),
+            "csv" => Ok(TableFormat::Csv),
+            _ => Err(format!("unknown table format '{}', expected text or csv", s)),
         }
-        
-        // Check if the entire string is made of this base repeated
-        let repeat_count = len / base_len;
-        let repeated = base.repeat(repeat_count);
-        
-        if repeated == s {
-            return true;
+    }
+}
+
+/// The integer type --width reads range bounds into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Width {
+    U64,
+    U128,
+    Big,
+}
+
+impl FromStr for Width {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "u64" => Ok(Width::U64),
+            "u128" => Ok(Width::U128),
+            "big" => Ok(Width::Big),
+            _ => Err(format!("unknown width '{}', expected u64, u128 or big", s)),
         }
     }
-    
-    false
 }
 
-fn find_invalid_ids_in_range(start: u64, end: u64) -> Vec<u64> {
-    let mut invalid_ids_set = HashSet::new();
-    
-    // For efficiency, we generate repeated sequences and check if they're in range
-    // rather than checking every number in the range
-    
-    // Determine the digit lengths we need to consider
-    let start_str = start.to_string();
-    let end_str = end.to_string();
-    let min_len = start_str.len();
-    let max_len = end_str.len();
-    
-    for total_len in min_len..=max_len {
-        // Try all possible base lengths that divide total_len
-        // and allow for at least 2 repetitions
-        for base_len in 1..=total_len / 2 {
-            if total_len % base_len != 0 {
-                continue;
-            }
-            
-            let repeat_count = total_len / base_len;
-            if repeat_count < 2 {
-                continue;
-            }
-            
-            // Generate all base sequences of the required length
-            let base_start = if base_len == 1 { 1 } else { 10u64.pow((base_len - 1) as u32) };
-            let base_end = 10u64.pow(base_len as u32) - 1;
-            
-            for base in base_start..=base_end {
-                // Create the repeated number
-                let base_str = base.to_string();
-                let repeated_str = base_str.repeat(repeat_count);
-                
-                // Check if the repeated string has the expected length
-                if repeated_str.len() != total_len {
-                    continue;
-                }
-                
-                let repeated: u64 = repeated_str.parse().unwrap();
-                
-                if repeated >= start && repeated <= end {
-                    invalid_ids_set.insert(repeated);
-                }
-            }
+fn main() -> ExitCode {
+    match run(Args::from_env()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}\n\n{}", err, USAGE);
+            ExitCode::FAILURE
         }
     }
-    
-    invalid_ids_set.into_iter().collect()
 }
 
-fn parse_input(input: &str) -> Vec<(u64, u64)> {
-    let input = input.trim();
-    let mut ranges = Vec::new();
-    
-    for part in input.split(',') {
-        let part = part.trim();
-        if part.is_empty() {
-            continue;
+fn run(mut args: Args) -> Result<(), ArgError> {
+    if args.flag("--help") {
+        println!("{}", USAGE);
+        return Ok(());
+    }
+    let policy: Option<RepeatPolicy> = args.parsed("--repeats")?;
+    let radix: Radix = args.parsed("--radix")?.unwrap_or_default();
+    let per_range = args.flag("--per-range");
+    let table: Option<TableFormat> = args.parsed("--table")?;
+    let strict = args.flag("--strict");
+    let width: Option<Width> = args.parsed("--width")?;
+    if args.flag("--batch") {
+        let options = [
+            ("--strict", strict),
+            ("--table", table.is_some()),
+            ("--per-range", per_range),
+            ("--width", width.is_some()),
+        ];
+        reject_with("--batch", &options)?;
+        if let Some(path) = args.finish()?.first() {
+            return Err(ArgError(format!("--batch reads stdin and takes no INPUT, got '{}'", path)));
         }
-        
-        let parts: Vec<&str> = part.split('-').collect();
-        if parts.len() == 2 {
-            let start: u64 = parts[0].parse().expect("Invalid start number");
-            let end: u64 = parts[1].parse().expect("Invalid end number");
-            ranges.push((start, end));
+        let policies = policy.map_or(vec![RepeatPolicy::PART1, RepeatPolicy::PART2], |policy| vec![policy]);
+        run_batch(&policies, radix);
+        return Ok(());
+    }
+    if width.is_some() {
+        reject_with("--width", &[("--strict", strict), ("--table", table.is_some()), ("--per-range", per_range)])?;
+    }
+    if table.is_some() {
+        reject_with("--table", &[("--per-range", per_range)])?;
+    }
+    let path = match &args.finish()?[..] {
+        [] => None,
+        [path] => Some(path.clone()),
+        [_, extra, ..] => return Err(ArgError(format!("unexpected argument '{}'", extra))),
+    };
+
+    let input = aoc_core::input::load_from(env!("CARGO_MANIFEST_DIR"), path);
+    if let Some(width) = width {
+        let policies = policy.map_or(vec![RepeatPolicy::PART1, RepeatPolicy::PART2], |policy| vec![policy]);
+        let origin = input.path.display().to_string();
+        match width {
+            Width::U64 => print_wide_sums::<u64>(&input.text, &origin, &policies, radix),
+            Width::U128 => print_wide_sums::<u128>(&input.text, &origin, &policies, radix),
+            Width::Big => print_wide_sums::<BigDecimal>(&input.text, &origin, &policies, radix),
+        }
+        return Ok(());
+    }
+    let ranges = if strict {
+        parse_ranges_strict(&input.text, radix).unwrap_or_else(|issues| {
+            for issue in issues {
+                eprintln!("error: {}: {}", input.path.display(), issue);
+            }
+            process::exit(1)
+        })
+    } else {
+        parse_ranges(&input.text, radix).unwrap_or_else(|err| {
+            eprint!("{}", err.render(&input.text, &input.path.display().to_string()));
+            process::exit(1)
+        })
+    };
+
+    if let Some(format) = table {
+        print_table(&ranges, policy.unwrap_or(RepeatPolicy::PART2), radix, format);
+        return Ok(());
+    }
+
+    let merged = merge_ranges(&ranges).len();
+    if merged < ranges.len() && !per_range {
+        println!("Merged {} ranges into {} non-overlapping ones", ranges.len(), merged);
+    }
+    let report = |label: &str, policy: RepeatPolicy| {
+        let totals = total_invalid_ids(&ranges, policy, radix);
+        let duplicates = totals.duplicates().count;
+        match per_range {
+            true if duplicates > 0 => println!("{}: {} ({} extra hits from overlapping ranges)", label, totals.per_range.sum, duplicates),
+            true => println!("{}: {}", label, totals.per_range.sum),
+            false if duplicates > 0 => println!("{}: {} ({} duplicate hits eliminated)", label, totals.union.sum, duplicates),
+            false => println!("{}: {}", label, totals.union.sum),
+        }
+    };
+    match policy {
+        Some(policy) => report(&format!("Sum of invalid IDs ({})", policy), policy),
+        None => {
+            report("Part 1 - Sum of invalid IDs", RepeatPolicy::PART1);
+            report("Part 2 - Sum of invalid IDs", RepeatPolicy::PART2);
         }
     }
-    
-    ranges
+    Ok(())
+}
+
+/// Fails on the first of `options` given together with `mode`, which
+/// would otherwise ignore it.
+fn reject_with(mode: &str, options: &[(&str, bool)]) -> Result<(), ArgError> {
+    match options.iter().find(|&&(_, given)| given) {
+        Some((option, _)) => Err(ArgError(format!("{} cannot be combined with {}", mode, option))),
+        None => Ok(()),
+    }
+}
+
+/// One row per invalid ID, range by range in input order, stopping quietly
+/// once stdout goes away, as it does under `| head`.
+fn print_table(ranges: &[(u64, u64)], policy: RepeatPolicy, radix: Radix, format: TableFormat) {
+    let _ = write_table(&mut BufWriter::new(io::stdout().lock()), ranges, policy, radix, format);
 }
 
-fn main() {
-    let input = fs::read_to_string("input.txt").expect("Failed to read input.txt");
-    let ranges = parse_input(&input);
-    
-    let mut total_sum: u64 = 0;
-    
-    for (start, end) in ranges {
-        let invalid_ids = find_invalid_ids_in_range(start, end);
-        for id in invalid_ids {
-            total_sum += id;
+fn write_table(
+    out: &mut impl Write,
+    ranges: &[(u64, u64)],
+    policy: RepeatPolicy,
+    radix: Radix,
+    format: TableFormat,
+) -> io::Result<()> {
+    let columns = ["range", "id", "base", "length", "repeats", "alternatives"];
+    let width = ranges.iter().map(|&(_, end)| radix.digit_count(end) as usize).max().unwrap_or(0);
+    let widths = [2 * width + 1, width, (width / 2).max(4), 6, 7, 0];
+    let mut write_row = |fields: [&str; 6]| match format {
+        TableFormat::Csv => writeln!(out, "{}", fields.join(",")),
+        TableFormat::Text => {
+            let padded: Vec<String> = fields.iter().zip(widths).map(|(field, width)| format!("{:<1$}", field, width)).collect();
+            writeln!(out, "{}", padded.join("  ").trim_end())
+        }
+    };
+    write_row(columns)?;
+    for &(start, end) in ranges {
+        let range = format!("{}-{}", radix.format(start), radix.format(end));
+        for id in InvalidIds::new(start, end, policy, radix) {
+            let decompositions = decompositions(id, radix);
+            let primitive = decompositions[0];
+            let alternatives: Vec<String> = decompositions[1..].iter().map(|d| d.format(radix)).collect();
+            write_row([
+                &range,
+                &radix.format(id),
+                &radix.format(primitive.block),
+                &primitive.len.to_string(),
+                &primitive.repeats.to_string(),
+                &alternatives.join(" "),
+            ])?;
         }
     }
-    
-    println!("Sum of all invalid IDs: {}", total_sum);
+    out.flush()
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_is_repeated_sequence() {
-        // Part 2: sequences repeated at least twice
-        assert!(is_repeated_sequence(55));
-        assert!(is_repeated_sequence(6464));
-        assert!(is_repeated_sequence(123123));
-        assert!(is_repeated_sequence(11));
-        assert!(is_repeated_sequence(22));
-        assert!(is_repeated_sequence(99));
-        assert!(is_repeated_sequence(1010));
-        
-        // New cases for part 2
-        assert!(is_repeated_sequence(111));      // 1 repeated 3 times
-        assert!(is_repeated_sequence(999));      // 9 repeated 3 times
-        assert!(is_repeated_sequence(1111111));  // 1 repeated 7 times
-        assert!(is_repeated_sequence(123123123)); // 123 repeated 3 times
-        assert!(is_repeated_sequence(1212121212)); // 12 repeated 5 times
-        assert!(is_repeated_sequence(565656));   // 56 repeated 3 times
-        assert!(is_repeated_sequence(824824824)); // 824 repeated 3 times
-        
-        assert!(!is_repeated_sequence(101));
-        assert!(!is_repeated_sequence(12));
-        assert!(!is_repeated_sequence(123));
-        assert!(!is_repeated_sequence(1234));
-    }
-
-    #[test]
-    fn test_example_part2() {
-        let input = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
-        let ranges = parse_input(input);
-        
-        let mut total_sum: u64 = 0;
+/// Answers ranges from stdin line by line, flushing after each line so a
+/// caller feeding ranges through a pipe sees answers as it goes.
+fn run_batch(policies: &[RepeatPolicy], radix: Radix) {
+    let prefixes: Vec<PrefixTally> = policies.iter().map(|&policy| PrefixTally::new(policy, radix)).collect();
+    let mut out = BufWriter::new(io::stdout().lock());
+    let mut malformed = 0;
+    for (number, line) in io::stdin().lock().lines().enumerate() {
+        let line = line.unwrap_or_else(|err| {
+            eprintln!("error: cannot read stdin: {}", err);
+            process::exit(1)
+        });
+        let ranges = match parse_ranges(&line, radix) {
+            Ok(ranges) => ranges,
+            Err(err) => {
+                malformed += 1;
+                eprintln!("error: {}", ParseError { line: number + 1, ..err });
+                continue;
+            }
+        };
         for (start, end) in ranges {
-            let invalid_ids = find_invalid_ids_in_range(start, end);
-            for id in &invalid_ids {
-                total_sum += id;
+            let _ = write!(out, "{}-{}", radix.format(start), radix.format(end));
+            for prefix in &prefixes {
+                let tally = prefix.range(start, end);
+                let _ = write!(out, " {} {}", tally.count, tally.sum);
             }
+            let _ = writeln!(out);
+        }
+        // Stdout has gone away, so nobody is reading the answers any more.
+        if out.flush().is_err() {
+            break;
         }
-        
-        assert_eq!(total_sum, 4174379265);
-    }
-
-    #[test]
-    fn test_individual_ranges_part2() {
-        // 11-22 still has two invalid IDs, 11 and 22
-        let ids = find_invalid_ids_in_range(11, 22);
-        assert!(ids.contains(&11));
-        assert!(ids.contains(&22));
-        assert_eq!(ids.len(), 2);
-
-        // 95-115 now has two invalid IDs, 99 and 111
-        let ids = find_invalid_ids_in_range(95, 115);
-        assert!(ids.contains(&99));
-        assert!(ids.contains(&111));
-        assert_eq!(ids.len(), 2);
-
-        // 998-1012 now has two invalid IDs, 999 and 1010
-        let ids = find_invalid_ids_in_range(998, 1012);
-        assert!(ids.contains(&999));
-        assert!(ids.contains(&1010));
-        assert_eq!(ids.len(), 2);
-
-        // 1188511880-1188511890 still has one invalid ID, 1188511885
-        let ids = find_invalid_ids_in_range(1188511880, 1188511890);
-        assert!(ids.contains(&1188511885));
-        assert_eq!(ids.len(), 1);
-
-        // 222220-222224 still has one invalid ID, 222222
-        let ids = find_invalid_ids_in_range(222220, 222224);
-        assert!(ids.contains(&222222));
-        assert_eq!(ids.len(), 1);
-
-        // 1698522-1698528 still contains no invalid IDs
-        let ids = find_invalid_ids_in_range(1698522, 1698528);
-        assert_eq!(ids.len(), 0);
-
-        // 446443-446449 still has one invalid ID, 446446
-        let ids = find_invalid_ids_in_range(446443, 446449);
-        assert!(ids.contains(&446446));
-        assert_eq!(ids.len(), 1);
-
-        // 38593856-38593862 still has one invalid ID, 38593859
-        let ids = find_invalid_ids_in_range(38593856, 38593862);
-        assert!(ids.contains(&38593859));
-        assert_eq!(ids.len(), 1);
-
-        // 565653-565659 now has one invalid ID, 565656
-        let ids = find_invalid_ids_in_range(565653, 565659);
-        assert!(ids.contains(&565656));
-        assert_eq!(ids.len(), 1);
-
-        // 824824821-824824827 now has one invalid ID, 824824824
-        let ids = find_invalid_ids_in_range(824824821, 824824827);
-        assert!(ids.contains(&824824824));
-        assert_eq!(ids.len(), 1);
-
-        // 2121212118-2121212124 now has one invalid ID, 2121212121
-        let ids = find_invalid_ids_in_range(2121212118, 2121212124);
-        assert!(ids.contains(&2121212121));
-        assert_eq!(ids.len(), 1);
+    }
+    if malformed > 0 {
+        eprintln!("error: skipped {} malformed input lines", malformed);
+        process::exit(1);
+    }
+}
+
+fn print_wide_sums<T: IdWidth>(text: &str, origin: &str, policies: &[RepeatPolicy], radix: Radix) {
+    let ranges: Vec<(T, T)> = parse_ranges_wide(text, radix).unwrap_or_else(|err| {
+        eprint!("{}", err.render(text, origin));
+        process::exit(1)
+    });
+    for &policy in policies {
+        let tally = total_invalid_ids_wide(&ranges, policy, radix);
+        println!("Sum of invalid IDs ({}): {} ({} IDs)", policy, tally.sum, tally.count);
     }
 }
@@ -0,0 +1,76 @@
+use crate::Radix;
+
+/// One way of writing an ID as a block of digits repeated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Decomposition {
+    /// The block's value. It starts with the ID's first digit, so it never
+    /// has a leading zero.
+    pub block: u64,
+    /// How many digits the block has.
+    pub len: u32,
+    pub repeats: u32,
+}
+
+impl Decomposition {
+    /// The block in `radix` followed by its repeat count, as in `12x5`.
+    pub fn format(self, radix: Radix) -> String {
+        format!("{}x{}", radix.format(self.block), self.repeats)
+    }
+}
+
+/// Every way of writing `n` in `radix` as a block repeated at least twice,
+/// shortest block first.
+///
+/// The first, if any, is the primitive base with the most repeats; every
+/// later block is that base repeated, so 111111 is `1x6`, `11x3`, `111x2`.
+pub fn decompositions(n: u64, radix: Radix) -> Vec<Decomposition> {
+    let len = radix.digit_count(n);
+    (1..=len / 2)
+        .filter(|&block_len| len.is_multiple_of(block_len))
+        .filter_map(|block_len| {
+            // An ID is an `L`-digit block repeated exactly when it is a
+            // multiple of 1 0..0 1 0..0 1: the quotient is then the block.
+            let multiplier = (radix.pow(len) - 1) / (radix.pow(block_len) - 1);
+            (n as u128).is_multiple_of(multiplier).then(|| Decomposition {
+                block: (n as u128 / multiplier) as u64,
+                len: block_len,
+                repeats: len / block_len,
+            })
+        })
+        .collect()
+}
+
+/// The shortest block `n` is made of, with how often it repeats.
+pub fn primitive(n: u64, radix: Radix) -> Option<Decomposition> {
+    decompositions(n, radix).into_iter().next()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{is_repeated_sequence, RepeatPolicy};
+
+    #[test]
+    fn test_decompositions() {
+        let twelve = Decomposition { block: 12, len: 2, repeats: 5 };
+        assert_eq!(decompositions(1212121212, Radix::DECIMAL), vec![twelve]);
+        let formatted: Vec<String> = decompositions(111111, Radix::DECIMAL)
+            .into_iter()
+            .map(|d| d.format(Radix::DECIMAL))
+            .collect();
+        assert_eq!(formatted, ["1x6", "11x3", "111x2"]);
+        assert_eq!(primitive(0xabab, Radix::HEX).unwrap().format(Radix::HEX), "abx2");
+        assert_eq!(primitive(101, Radix::DECIMAL), None);
+        assert_eq!(primitive(7, Radix::DECIMAL), None);
+    }
+
+    #[test]
+    fn test_decomposable_ids_are_invalid() {
+        for radix in [Radix::BINARY, Radix::DECIMAL, Radix::new(7).unwrap()] {
+            for n in 0..20_000 {
+                let invalid = is_repeated_sequence(n, RepeatPolicy::PART2, radix);
+                assert_eq!(primitive(n, radix).is_some(), invalid, "{} in base {}", n, radix);
+            }
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use aoc_core::BigDecimal;
+use std::fmt;
+use std::str::FromStr;
+
+/// The base IDs are written in, from 2 to 36, with digits `0-9` then `a-z`.
+///
+/// IDs never have leading zeros in any base, and neither may the block an
+/// invalid ID repeats: its first digit is the ID's first digit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Radix(u32);
+
+impl Radix {
+    pub const BINARY: Radix = Radix(2);
+    pub const OCTAL: Radix = Radix(8);
+    pub const DECIMAL: Radix = Radix(10);
+    pub const HEX: Radix = Radix(16);
+
+    pub fn new(radix: u32) -> Option<Radix> {
+        (2..=36).contains(&radix).then_some(Radix(radix))
+    }
+
+    pub fn get(self) -> u32 {
+        self.0
+    }
+
+    /// `radix^exp`, which fits for every exponent up to one past the
+    /// number of digits in `u64::MAX`.
+    pub fn pow(self, exp: u32) -> u128 {
+        (self.0 as u128).pow(exp)
+    }
+
+    /// How many digits `n` has, counting zero as one digit.
+    pub fn digit_count(self, n: u64) -> u32 {
+        n.checked_ilog(self.0 as u64).unwrap_or(0) + 1
+    }
+
+    /// The digits of `n`, most significant first.
+    pub fn digits(self, mut n: u64) -> Vec<u8> {
+        let mut digits = Vec::with_capacity(self.digit_count(n) as usize);
+        loop {
+            digits.push((n % self.0 as u64) as u8);
+            n /= self.0 as u64;
+            if n == 0 {
+                break;
+            }
+        }
+        digits.reverse();
+        digits
+    }
+
+    /// `n` written out in this base, in lowercase and without a prefix.
+    pub fn format(self, n: u64) -> String {
+        self.digits(n)
+            .into_iter()
+            .map(|d| char::from_digit(d as u32, self.0).unwrap())
+            .collect()
+    }
+
+    /// The prefix conventionally marking numbers in this base, if any.
+    pub fn prefix(self) -> Option<&'static str> {
+        match self.0 {
+            2 => Some("0b"),
+            8 => Some("0o"),
+            16 => Some("0x"),
+            _ => None,
+        }
+    }
+
+    /// Parses a number in this base, in either case and with or without
+    /// the base's own prefix. Other prefixes are read as digits, so `0b11`
+    /// is 2833 in hex.
+    pub fn parse(self, s: &str) -> Option<u64> {
+        let digits = self.strip_prefix(s);
+        // `from_str_radix` would also take a sign.
+        if !self.is_numeral(digits) {
+            return None;
+        }
+        u64::from_str_radix(digits, self.0).ok()
+    }
+
+    /// Parses a number of any size in this base, like [`Radix::parse`].
+    pub fn parse_big(self, s: &str) -> Option<BigDecimal> {
+        BigDecimal::from_str_radix(self.strip_prefix(s), self.0)
+    }
+
+    /// `s` without the base's own prefix, in either case, if it has one.
+    pub fn strip_prefix(self, s: &str) -> &str {
+        match self.prefix() {
+            Some(prefix) => s.strip_prefix(prefix).or_else(|| s.strip_prefix(&prefix.to_uppercase())).unwrap_or(s),
+            None => s,
+        }
+    }
+
+    /// Whether `s` is made only of digits of this base, however many.
+    pub fn is_numeral(self, s: &str) -> bool {
+        !s.is_empty() && s.chars().all(|c| c.is_digit(self.0))
+    }
+}
+
+impl Default for Radix {
+    fn default() -> Self {
+        Radix::DECIMAL
+    }
+}
+
+impl fmt::Display for Radix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for Radix {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse()
+            .ok()
+            .and_then(Radix::new)
+            .ok_or_else(|| format!("invalid radix '{}', expected 2 to 36", s))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_digits_and_format() {
+        assert_eq!(Radix::HEX.digits(0x1f), vec![1, 15]);
+        assert_eq!(Radix::BINARY.format(0), "0");
+        assert_eq!(Radix::new(36).unwrap().format(36 * 36 - 1), "zz");
+        assert_eq!(Radix::BINARY.digit_count(u64::MAX), 64);
+        assert_eq!(Radix::DECIMAL.digit_count(u64::MAX), 20);
+        assert_eq!(Radix::BINARY.pow(64), 1 << 64);
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Radix::HEX.parse("0x1f"), Some(31));
+        assert_eq!(Radix::HEX.parse("0X1F"), Some(31));
+        assert_eq!(Radix::HEX.parse("ff"), Some(255));
+        assert_eq!(Radix::HEX.parse("0b11"), Some(0xb11));
+        assert_eq!(Radix::BINARY.parse("0b101"), Some(5));
+        assert_eq!(Radix::DECIMAL.parse("0x1f"), None);
+        assert_eq!(Radix::OCTAL.parse("0o"), None);
+        assert_eq!(Radix::DECIMAL.parse("+5"), None);
+        assert_eq!(Radix::HEX.parse("1_0000_0000_0000_0000"), None);
+        assert_eq!(Radix::HEX.parse("10000000000000000"), None);
+        assert_eq!(Radix::HEX.parse_big("0x10000000000000000"), Some(BigDecimal::from(1u128 << 64)));
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("16".parse(), Ok(Radix::HEX));
+        assert!("1".parse::<Radix>().is_err());
+        assert!("37".parse::<Radix>().is_err());
+    }
+}
@@ -0,0 +1,204 @@
+//! A slow but obviously correct reference for the invalid ID search, used
+//! to check the lazy invalid ID iterator and the closed-form tally on
+//! small random ranges in every radix, and the merging of overlapping
+//! range lists.
+
+use std::collections::BTreeSet;
+use aoc_core::oracle::{self, Check, Oracle};
+use aoc_core::rng::Rng;
+use crate::{
+    find_invalid_ids_in_range, tally_invalid_ids, tally_invalid_ids_wide, total_invalid_ids, InvalidIds, Radix,
+    RepeatPolicy, Tally, Totals,
+};
+
+/// Whether the digits of `n` in `radix` are some block repeated a number
+/// of times `policy` allows, checked by comparing every block of every
+/// length that divides them.
+pub fn is_invalid(n: u64, policy: RepeatPolicy, radix: Radix) -> bool {
+    let digits = radix.format(n).into_bytes();
+    (1..digits.len())
+        .filter(|&len| digits.len().is_multiple_of(len) && policy.allows(digits.len() / len))
+        .any(|len| digits.chunks(len).all(|block| block == &digits[..len]))
+}
+
+/// Every invalid ID in `start..=end`, found by testing each ID in turn.
+pub fn invalid_ids_in_range(start: u64, end: u64, policy: RepeatPolicy, radix: Radix) -> Vec<u64> {
+    (start..=end).filter(|&n| is_invalid(n, policy, radix)).collect()
+}
+
+/// A range of up to about two thousand IDs below ten million, often
+/// straddling a power of the radix where the lengths change, with any
+/// radix and repeat policy.
+fn small_case(rng: &mut Rng) -> Case {
+    // Decimal is the puzzle's own radix, so give it extra weight.
+    let radix = if rng.chance(30) { Radix::DECIMAL } else { Radix::new(rng.range(2..=36) as u32).unwrap() };
+    let most = (1..).take_while(|&len| radix.pow(len) <= 10_000_000).last().unwrap();
+    let len = rng.range(1..=most as u64) as u32;
+    let power = radix.pow(len) as u64;
+    let start = if rng.chance(30) {
+        power.saturating_sub(rng.range(0..=500))
+    } else {
+        rng.range(radix.pow(len - 1) as u64..=power - 1)
+    };
+    let k = rng.range(1..=4) as usize;
+    let policy = *rng.choose(&[
+        RepeatPolicy::ExactlyTwice,
+        RepeatPolicy::AtLeast(k),
+        RepeatPolicy::Exactly(k),
+        RepeatPolicy::AtMost(k),
+    ]);
+    ((start, start + rng.range(0..=2000)), policy, radix)
+}
+
+type Case = ((u64, u64), RepeatPolicy, Radix);
+
+fn shrink(&(range, policy, radix): &Case) -> Vec<Case> {
+    let mut candidates: Vec<Case> = oracle::shrink_range(range).into_iter().map(|range| (range, policy, radix)).collect();
+    if radix != Radix::DECIMAL {
+        candidates.push((range, policy, Radix::DECIMAL));
+    }
+    candidates
+}
+
+fn render(&((start, end), policy, radix): &Case) -> String {
+    format!("{}-{} (base {} is {}-{}, repeats {})", start, end, radix, radix.format(start), radix.format(end), policy)
+}
+
+/// The union and per-range totals of a range list, found by collecting
+/// the invalid IDs of every range one at a time.
+pub fn totals_by_collecting(ranges: &[(u64, u64)], policy: RepeatPolicy, radix: Radix) -> Totals {
+    let mut union = BTreeSet::new();
+    let mut per_range = Tally::default();
+    for &(start, end) in ranges {
+        for id in invalid_ids_in_range(start, end, policy, radix) {
+            union.insert(id);
+            per_range = per_range + Tally { count: 1, sum: id as u128 };
+        }
+    }
+    Totals {
+        union: Tally {
+            count: union.len() as u64,
+            sum: union.iter().map(|&id| id as u128).sum(),
+        },
+        per_range,
+    }
+}
+
+/// A few decimal ranges crowded below ten thousand so they often overlap.
+fn range_list(rng: &mut Rng) -> (Vec<(u64, u64)>, RepeatPolicy) {
+    let ranges = (0..rng.range(1..=5))
+        .map(|_| {
+            let start = rng.range(1..=9_000);
+            (start, start + rng.range(0..=1_000))
+        })
+        .collect();
+    (ranges, *rng.choose(&[RepeatPolicy::PART1, RepeatPolicy::PART2]))
+}
+
+pub fn oracles() -> Vec<Box<dyn Check>> {
+    vec![
+        Box::new(Oracle {
+            name: "invalid IDs in a range",
+            generate: small_case,
+            shrink,
+            render,
+            fast: |&((start, end), policy, radix)| find_invalid_ids_in_range(start, end, policy, radix),
+            reference: |&((start, end), policy, radix)| invalid_ids_in_range(start, end, policy, radix),
+        }),
+        Box::new(Oracle {
+            name: "closed-form tally",
+            generate: small_case,
+            shrink,
+            render,
+            fast: |&((start, end), policy, radix)| tally_invalid_ids(start, end, policy, radix),
+            reference: |&((start, end), policy, radix)| {
+                let ids = invalid_ids_in_range(start, end, policy, radix);
+                Tally {
+                    count: ids.len() as u64,
+                    sum: ids.iter().map(|&id| id as u128).sum(),
+                }
+            },
+        }),
+        Box::new(Oracle {
+            name: "arbitrary-width tally",
+            generate: small_case,
+            shrink,
+            render,
+            fast: |&((start, end), policy, radix)| {
+                let tally = tally_invalid_ids_wide(&start, &end, policy, radix);
+                (tally.count.to_string(), tally.sum.to_string())
+            },
+            reference: |&((start, end), policy, radix)| {
+                let ids = invalid_ids_in_range(start, end, policy, radix);
+                (ids.len().to_string(), ids.iter().map(|&id| id as u128).sum::<u128>().to_string())
+            },
+        }),
+        Box::new(Oracle {
+            name: "paging through invalid IDs",
+            generate: small_case,
+            shrink,
+            render,
+            // Descending, every third from each end, and from the middle on.
+            fast: |&((start, end), policy, radix)| {
+                let ids = InvalidIds::new(start, end, policy, radix);
+                let mut from_middle = ids.clone();
+                from_middle.skip_to(start + (end - start) / 2);
+                (
+                    ids.clone().rev().collect::<Vec<_>>(),
+                    ids.clone().step_by(3).collect::<Vec<_>>(),
+                    ids.rev().step_by(3).collect::<Vec<_>>(),
+                    from_middle.collect::<Vec<_>>(),
+                )
+            },
+            reference: |&((start, end), policy, radix)| {
+                let ids = invalid_ids_in_range(start, end, policy, radix);
+                let middle = start + (end - start) / 2;
+                (
+                    ids.iter().rev().copied().collect(),
+                    ids.iter().step_by(3).copied().collect(),
+                    ids.iter().rev().step_by(3).copied().collect(),
+                    ids.iter().filter(|&&id| id >= middle).copied().collect(),
+                )
+            },
+        }),
+        Box::new(Oracle {
+            name: "totals over overlapping ranges",
+            generate: range_list,
+            shrink: |(ranges, policy)| {
+                oracle::shrink_vec(ranges, |&range| oracle::shrink_range(range))
+                    .into_iter()
+                    .map(|ranges| (ranges, *policy))
+                    .collect()
+            },
+            render: |(ranges, policy)| {
+                let ranges: Vec<String> = ranges.iter().map(|(start, end)| format!("{}-{}", start, end)).collect();
+                format!("{} (repeats {})", ranges.join(","), policy)
+            },
+            fast: |(ranges, policy)| total_invalid_ids(ranges, *policy, Radix::DECIMAL),
+            reference: |(ranges, policy)| totals_by_collecting(ranges, *policy, Radix::DECIMAL),
+        }),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_invalid() {
+        let (policy, decimal) = (RepeatPolicy::PART2, Radix::DECIMAL);
+        assert!(is_invalid(11, policy, decimal) && is_invalid(111, policy, decimal) && is_invalid(1212121212, policy, decimal));
+        assert!(!is_invalid(7, policy, decimal) && !is_invalid(101, policy, decimal) && !is_invalid(1231, policy, decimal));
+        assert!(!is_invalid(111, RepeatPolicy::PART1, decimal) && is_invalid(123123, RepeatPolicy::PART1, decimal));
+        assert!(is_invalid(0xabab, policy, Radix::HEX) && !is_invalid(0xabab, policy, decimal));
+    }
+
+    #[test]
+    fn test_fast_matches_reference() {
+        for check in oracles() {
+            if let Err(mismatch) = check.run(0, 300) {
+                panic!("{}: {}", check.name(), mismatch);
+            }
+        }
+    }
+}
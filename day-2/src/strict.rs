@@ -0,0 +1,170 @@
+use crate::Radix;
+use std::collections::HashMap;
+use std::fmt;
+
+/// Something strict parsing rejects in a range list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Problem {
+    /// Not two bounds joined by `-`.
+    Malformed,
+    /// A bound that is not written in the radix.
+    NotANumber { radix: Radix },
+    /// A bound with more digits than fit in 64 bits.
+    TooLarge,
+    /// A range whose start is after its end.
+    Reversed,
+    /// Nothing between two commas, or a comma with no range before or after.
+    Empty,
+    /// The same range again; `first` is the byte offset of its first copy.
+    Duplicate { first: usize },
+}
+
+/// A problem found at byte `offset` of the input, in the text `chunk`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeIssue {
+    pub offset: usize,
+    pub chunk: String,
+    pub problem: Problem,
+}
+
+impl fmt::Display for RangeIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "byte {}: ", self.offset)?;
+        match &self.problem {
+            Problem::Malformed => write!(f, "expected a range like `11-22`, found `{}`", self.chunk),
+            Problem::NotANumber { radix } => write!(f, "`{}` is not a number in base {}", self.chunk, radix),
+            Problem::TooLarge => write!(f, "`{}` does not fit in 64 bits", self.chunk),
+            Problem::Reversed => write!(f, "range `{}` starts after it ends", self.chunk),
+            Problem::Empty => write!(f, "empty range between separators"),
+            Problem::Duplicate { first } => write!(f, "range `{}` repeats the one at byte {}", self.chunk, first),
+        }
+    }
+}
+
+/// Parses ranges separated by commas, whitespace or newlines, reporting
+/// every problem instead of stopping at the first or letting it through.
+///
+/// Unlike [`parse_ranges`](crate::parse_ranges), reversed and duplicate
+/// ranges are rejected, as are empty entries such as the middle of `,,`.
+pub fn parse_ranges_strict(input: &str, radix: Radix) -> Result<Vec<(u64, u64)>, Vec<RangeIssue>> {
+    let mut ranges = Vec::new();
+    let mut issues = Vec::new();
+    let mut first_seen = HashMap::new();
+    let issue = |offset: usize, chunk: &str, problem: Problem| RangeIssue {
+        offset,
+        chunk: chunk.to_string(),
+        problem,
+    };
+
+    // The offset of the last comma with no range after it yet.
+    let mut open_comma = Some(None);
+    for (offset, chunk) in chunks(input) {
+        if chunk == "," {
+            if let Some(comma) = open_comma {
+                issues.push(issue(comma.unwrap_or(offset), "", Problem::Empty));
+            }
+            open_comma = Some(Some(offset));
+            continue;
+        }
+        open_comma = None;
+
+        let Some((start, end)) = chunk.split_once('-').filter(|(start, end)| !start.is_empty() && !end.is_empty()) else {
+            issues.push(issue(offset, chunk, Problem::Malformed));
+            continue;
+        };
+        let bound = |text: &str, at: usize| {
+            let digits = radix.strip_prefix(text);
+            match radix.parse(text) {
+                Some(value) => Ok(value),
+                None if radix.is_numeral(digits) => Err(issue(at, text, Problem::TooLarge)),
+                None => Err(issue(at, text, Problem::NotANumber { radix })),
+            }
+        };
+        let (start, end) = match (bound(start, offset), bound(end, offset + start.len() + 1)) {
+            (Ok(start), Ok(end)) => (start, end),
+            (start, end) => {
+                issues.extend(start.err());
+                issues.extend(end.err());
+                continue;
+            }
+        };
+        if start > end {
+            issues.push(issue(offset, chunk, Problem::Reversed));
+        } else if let Some(&first) = first_seen.get(&(start, end)) {
+            issues.push(issue(offset, chunk, Problem::Duplicate { first }));
+        } else {
+            first_seen.insert((start, end), offset);
+            ranges.push((start, end));
+        }
+    }
+    if let Some(Some(comma)) = open_comma {
+        issues.push(issue(comma, "", Problem::Empty));
+    }
+
+    if issues.is_empty() {
+        Ok(ranges)
+    } else {
+        Err(issues)
+    }
+}
+
+/// Splits `input` into commas and the runs of text between separators,
+/// each with its byte offset. Whitespace only separates.
+fn chunks(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    let mut rest = input.char_indices().peekable();
+    std::iter::from_fn(move || {
+        while rest.next_if(|(_, c)| c.is_whitespace()).is_some() {}
+        let (start, c) = rest.next()?;
+        if c == ',' {
+            return Some((start, &input[start..start + 1]));
+        }
+        let mut end = start + c.len_utf8();
+        while let Some((i, c)) = rest.next_if(|&(_, c)| c != ',' && !c.is_whitespace()) {
+            end = i + c.len_utf8();
+        }
+        Some((start, &input[start..end]))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn problems(input: &str) -> Vec<(usize, Problem)> {
+        parse_ranges_strict(input, Radix::DECIMAL)
+            .unwrap_err()
+            .into_iter()
+            .map(|issue| (issue.offset, issue.problem))
+            .collect()
+    }
+
+    #[test]
+    fn test_separators() {
+        let ranges = parse_ranges_strict("11-22,95-115\n998-1012  1188511880-1188511890,\n222220-222224\n", Radix::DECIMAL);
+        assert_eq!(ranges.unwrap(), vec![(11, 22), (95, 115), (998, 1012), (1188511880, 1188511890), (222220, 222224)]);
+        assert_eq!(parse_ranges_strict("0x1f-0xff", Radix::HEX).unwrap(), vec![(0x1f, 0xff)]);
+        assert_eq!(parse_ranges_strict(" \n", Radix::DECIMAL).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn test_every_problem_is_reported() {
+        assert_eq!(
+            problems("11-22,50-10,11-22 7 1-x"),
+            vec![
+                (6, Problem::Reversed),
+                (12, Problem::Duplicate { first: 0 }),
+                (18, Problem::Malformed),
+                (22, Problem::NotANumber { radix: Radix::DECIMAL }),
+            ]
+        );
+        assert_eq!(problems("1-99999999999999999999"), vec![(2, Problem::TooLarge)]);
+        assert_eq!(problems(",1-2,,3-4,"), vec![(0, Problem::Empty), (4, Problem::Empty), (9, Problem::Empty)]);
+        assert_eq!(problems("-5-10"), vec![(0, Problem::Malformed)]);
+    }
+
+    #[test]
+    fn test_display() {
+        let issues = parse_ranges_strict("1-2\n50-10", Radix::DECIMAL).unwrap_err();
+        assert_eq!(issues[0].to_string(), "byte 4: range `50-10` starts after it ends");
+    }
+}
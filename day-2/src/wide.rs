@@ -0,0 +1,307 @@
+//! The day-2 pipeline written once for IDs of any width. Range bounds are
+//! read into `u64`, `u128` or [`BigDecimal`], and the tally and the range
+//! merging run on a type wide enough that no total can overflow.
+
+use crate::{mobius, BigDecimal, Radix, RepeatPolicy};
+use aoc_core::parse::ParseError;
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, Div, Mul, Sub};
+
+/// The integer arithmetic the tally needs.
+pub trait Int:
+    Clone + Ord + Default + fmt::Debug + From<u64>
+    + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self>
+{
+    /// `radix^exp`.
+    fn power(radix: Radix, exp: u32) -> Self;
+
+    /// How many digits the value has in `radix`, counting zero as one digit.
+    fn len_in(&self, radix: Radix) -> u32;
+
+    fn to_big(&self) -> BigDecimal;
+}
+
+impl Int for u128 {
+    fn power(radix: Radix, exp: u32) -> Self {
+        (radix.get() as u128).pow(exp)
+    }
+
+    fn len_in(&self, radix: Radix) -> u32 {
+        self.checked_ilog(radix.get() as u128).unwrap_or(0) + 1
+    }
+
+    fn to_big(&self) -> BigDecimal {
+        BigDecimal::from(*self)
+    }
+}
+
+impl Int for BigDecimal {
+    fn power(radix: Radix, exp: u32) -> Self {
+        BigDecimal::pow(radix.get(), exp)
+    }
+
+    fn len_in(&self, radix: Radix) -> u32 {
+        self.digit_count(radix.get())
+    }
+
+    fn to_big(&self) -> BigDecimal {
+        self.clone()
+    }
+}
+
+/// An integer type range bounds are read into, which decides how wide an
+/// ID may be.
+pub trait IdWidth: Clone + Ord + fmt::Debug + fmt::Display {
+    /// How wide the type is, for error messages.
+    const NAME: &'static str;
+
+    /// The type IDs are tallied in, which holds one past the largest power
+    /// of the radix an ID reaches and any sum of IDs.
+    type Wide: Int;
+
+    fn widen(&self) -> Self::Wide;
+
+    /// The value as this type, if it fits.
+    fn from_big(n: &BigDecimal) -> Option<Self>;
+}
+
+impl IdWidth for u64 {
+    const NAME: &'static str = "64 bits";
+    type Wide = u128;
+
+    fn widen(&self) -> u128 {
+        *self as u128
+    }
+
+    fn from_big(n: &BigDecimal) -> Option<Self> {
+        n.to_u128()?.try_into().ok()
+    }
+}
+
+impl IdWidth for u128 {
+    const NAME: &'static str = "128 bits";
+    type Wide = BigDecimal;
+
+    fn widen(&self) -> BigDecimal {
+        BigDecimal::from(*self)
+    }
+
+    fn from_big(n: &BigDecimal) -> Option<Self> {
+        n.to_u128()
+    }
+}
+
+impl IdWidth for BigDecimal {
+    const NAME: &'static str = "any size";
+    type Wide = BigDecimal;
+
+    fn widen(&self) -> BigDecimal {
+        self.clone()
+    }
+
+    fn from_big(n: &BigDecimal) -> Option<Self> {
+        Some(n.clone())
+    }
+}
+
+/// How many invalid IDs some ranges hold and what they add up to, in `N`,
+/// by default exactly however large either gets.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WideTally<N = BigDecimal> {
+    pub count: N,
+    pub sum: N,
+}
+
+impl<N: Int> WideTally<N> {
+    pub fn to_big(&self) -> WideTally {
+        WideTally {
+            count: self.count.to_big(),
+            sum: self.sum.to_big(),
+        }
+    }
+}
+
+impl<N: Int> Add for WideTally<N> {
+    type Output = WideTally<N>;
+
+    fn add(self, other: WideTally<N>) -> WideTally<N> {
+        WideTally {
+            count: self.count + other.count,
+            sum: self.sum + other.sum,
+        }
+    }
+}
+
+impl<N: Int> Sub for WideTally<N> {
+    type Output = WideTally<N>;
+
+    fn sub(self, other: WideTally<N>) -> WideTally<N> {
+        WideTally {
+            count: self.count - other.count,
+            sum: self.sum - other.sum,
+        }
+    }
+}
+
+impl<N: Int> Sum for WideTally<N> {
+    fn sum<I: Iterator<Item = WideTally<N>>>(iter: I) -> WideTally<N> {
+        iter.fold(WideTally::default(), Add::add)
+    }
+}
+
+/// Parses comma-separated ranges whose bounds are written in `radix`, with
+/// or without its prefix, into bounds of type `T`.
+pub fn parse_ranges_wide<T: IdWidth>(input: &str, radix: Radix) -> Result<Vec<(T, T)>, ParseError> {
+    let mut ranges = Vec::new();
+    for part in input.trim().split(',') {
+        let part = part.trim();
+        if part.is_empty() {
+            continue;
+        }
+        let Some((start, end)) = part.split_once('-') else {
+            return Err(ParseError::at(input, part, "a range like `11-22`"));
+        };
+        ranges.push((parse_bound(input, start, radix, "a range start")?, parse_bound(input, end, radix, "a range end")?));
+    }
+    Ok(ranges)
+}
+
+fn parse_bound<T: IdWidth>(source: &str, field: &str, radix: Radix, what: &str) -> Result<T, ParseError> {
+    let trimmed = field.trim();
+    let trimmed = if trimmed.is_empty() { field } else { trimmed };
+    let expected = match radix {
+        Radix::DECIMAL => what.to_string(),
+        _ => format!("{} in base {}", what, radix),
+    };
+    let value = radix.parse_big(trimmed).ok_or_else(|| ParseError::at(source, trimmed, &expected))?;
+    T::from_big(&value).ok_or_else(|| ParseError::at(source, trimmed, format!("{} that fits in {}", expected, T::NAME)))
+}
+
+/// Counts and sums the invalid IDs in `start..=end` as
+/// [`tally_invalid_ids`](crate::tally_invalid_ids) explains, in the width's
+/// own tally type.
+pub fn tally_in<T: IdWidth>(start: &T, end: &T, policy: RepeatPolicy, radix: Radix) -> WideTally<T::Wide> {
+    let (start, end) = (start.widen(), end.widen());
+    if start > end {
+        return WideTally::default();
+    }
+    let one = T::Wide::from(1);
+    (start.len_in(radix)..=end.len_in(radix))
+        .map(|len| {
+            // The part of the range with exactly `len` digits.
+            let lo = start.clone().max(T::Wide::power(radix, len - 1));
+            let hi = end.clone().min(T::Wide::power(radix, len) - one.clone());
+            tally_of_length(&lo, &hi, len, policy, radix)
+        })
+        .sum()
+}
+
+/// Count and sum of the invalid `len`-digit IDs in `lo..=hi`, which must
+/// all have `len` digits.
+pub(crate) fn tally_of_length<N: Int>(lo: &N, hi: &N, len: u32, policy: RepeatPolicy, radix: Radix) -> WideTally<N> {
+    let divisors = |n: u32| (1..=n).filter(move |&d| n.is_multiple_of(d));
+    let (mut added, mut removed) = (WideTally::default(), WideTally::default());
+    for period in divisors(len) {
+        let copies = len / period;
+        if !divisors(copies).any(|repeats| policy.allows(repeats as usize)) {
+            continue;
+        }
+        for block in divisors(period) {
+            match mobius(period / block) {
+                1 => added = added + repeats(lo, hi, radix, len, block),
+                -1 => removed = removed + repeats(lo, hi, radix, len, block),
+                _ => {}
+            }
+        }
+    }
+    added - removed
+}
+
+/// Count and sum of the `len`-digit IDs in `lo..=hi` that are some
+/// `block`-digit number repeated, whether or not a shorter block also works.
+fn repeats<N: Int>(lo: &N, hi: &N, radix: Radix, len: u32, block: u32) -> WideTally<N> {
+    let one = N::from(1);
+    let multiplier = (N::power(radix, len) - one.clone()) / (N::power(radix, block) - one.clone());
+    let first = ((lo.clone() + multiplier.clone() - one.clone()) / multiplier.clone()).max(N::power(radix, block - 1));
+    let last = (hi.clone() / multiplier.clone()).min(N::power(radix, block) - one.clone());
+    if first > last {
+        return WideTally::default();
+    }
+    let count = last.clone() - first.clone() + one;
+    WideTally {
+        sum: multiplier * ((first + last) * count.clone() / N::from(2)),
+        count,
+    }
+}
+
+/// Counts and sums the invalid IDs in `start..=end` exactly, however wide.
+pub fn tally_invalid_ids_wide<T: IdWidth>(start: &T, end: &T, policy: RepeatPolicy, radix: Radix) -> WideTally {
+    tally_in(start, end, policy, radix).to_big()
+}
+
+/// Sorts the ranges and merges those that overlap or touch, dropping empty
+/// ones, so that every ID is covered at most once.
+pub fn merge_ranges<T: IdWidth>(ranges: &[(T, T)]) -> Vec<(T, T)> {
+    let mut sorted: Vec<(T, T)> = ranges.iter().filter(|(start, end)| start <= end).cloned().collect();
+    sorted.sort_unstable();
+
+    let mut merged: Vec<(T, T)> = Vec::with_capacity(sorted.len());
+    for (start, end) in sorted {
+        match merged.last_mut() {
+            // Overlapping or adjacent, compared wide so the end plus one
+            // cannot overflow.
+            Some(last) if start.widen() <= last.1.widen() + T::Wide::from(1) => last.1 = last.1.clone().max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+/// The invalid IDs over every range, each counted once however many ranges
+/// hold it.
+pub fn total_invalid_ids_wide<T: IdWidth>(ranges: &[(T, T)], policy: RepeatPolicy, radix: Radix) -> WideTally {
+    merge_ranges(ranges).iter().map(|(start, end)| tally_invalid_ids_wide(start, end, policy, radix)).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tally_invalid_ids;
+
+    #[test]
+    fn test_matches_u64_tally() {
+        let ranges = [(0, 0), (11, 22), (95, 1012), (1_000_000, 123_456_789), (1 << 40, u64::MAX)];
+        for radix in [Radix::BINARY, Radix::DECIMAL, Radix::new(36).unwrap()] {
+            for (start, end) in ranges {
+                let narrow = tally_invalid_ids(start, end, RepeatPolicy::PART2, radix);
+                let wide = tally_invalid_ids_wide(&start, &end, RepeatPolicy::PART2, radix);
+                assert_eq!(wide.count.to_u128(), Some(narrow.count as u128));
+                assert_eq!(wide.sum.to_u128(), Some(narrow.sum));
+            }
+        }
+    }
+
+    #[test]
+    fn test_forty_digit_ids() {
+        let start = BigDecimal::pow(10, 39);
+        let end = BigDecimal::pow(10, 40) - 1u64.into();
+        let tally = tally_invalid_ids_wide(&start, &end, RepeatPolicy::PART1, Radix::DECIMAL);
+        // Every 20-digit block, doubled.
+        assert_eq!(tally.count.to_string(), "90000000000000000000");
+        assert_eq!(tally.sum.to_string(), "495000000000000000000449999999999999999955000000000000000000");
+        let ranges: Vec<(u128, u128)> = parse_ranges_wide("1-340282366920938463463374607431768211455", Radix::DECIMAL).unwrap();
+        let in_u128 = total_invalid_ids_wide(&ranges, RepeatPolicy::PART2, Radix::DECIMAL);
+        let ranges: Vec<(BigDecimal, BigDecimal)> = parse_ranges_wide("1-340282366920938463463374607431768211455", Radix::DECIMAL).unwrap();
+        assert_eq!(total_invalid_ids_wide(&ranges, RepeatPolicy::PART2, Radix::DECIMAL), in_u128);
+    }
+
+    #[test]
+    fn test_width_limits() {
+        let too_wide = "1-340282366920938463463374607431768211456";
+        assert!(parse_ranges_wide::<u128>(too_wide, Radix::DECIMAL).is_err());
+        assert!(parse_ranges_wide::<BigDecimal>(too_wide, Radix::DECIMAL).is_ok());
+        let err = parse_ranges_wide::<u64>("1-18446744073709551616", Radix::DECIMAL).unwrap_err();
+        assert_eq!(err.expected, "a range end that fits in 64 bits");
+    }
+}
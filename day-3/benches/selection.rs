@@ -0,0 +1,54 @@
+//! Times the joltage selection on banks of a million batteries, against the
+//! window scan it replaced where that finishes in reasonable time, and the
+//! profile of every battery count against a greedy run per count.
+//!
+//! Run with `cargo bench -p day-3`.
+
+use aoc_core::rng::Rng;
+use aoc_core::timing::{format_duration, timed};
+use day_3::{max_joltage_digits, Profile};
+use day_3::reference::max_joltage_by_window_scan;
+use std::hint::black_box;
+use std::time::Duration;
+
+const LENGTH: usize = 1_000_000;
+const ITERATIONS: usize = 5;
+
+/// The fastest of a few runs, which is the least disturbed by the machine.
+fn fastest(f: impl Fn() -> Vec<u8>) -> Duration {
+    (0..ITERATIONS).map(|_| timed(|| black_box(f())).1).min().unwrap()
+}
+
+fn main() {
+    let mut rng = Rng::new(1);
+    let bank: String = (0..LENGTH).map(|_| char::from_digit(rng.range(1..=9) as u32, 10).unwrap()).collect();
+
+    println!("{:>9}  {:>12}  {:>12}", "k", "stack", "window scan");
+    for k in [2, 12, 1_000, 10_000, LENGTH / 2, LENGTH - 12] {
+        let stack = fastest(|| max_joltage_digits(black_box(&bank), k));
+        // The scan is O(n·k); past a few thousand picks it takes minutes.
+        let scan = match k <= 1_000 {
+            true => format_duration(fastest(|| max_joltage_by_window_scan(black_box(&bank), k))),
+            false => "-".to_string(),
+        };
+        println!("{:>9}  {:>12}  {:>12}", k, format_duration(stack), scan);
+    }
+
+    let (_, build) = timed(|| black_box(Profile::new(black_box(&bank))));
+    println!("\nprofile of {} batteries built in {}", LENGTH, format_duration(build));
+
+    // Every count of a shorter bank: the greedy redoes the whole bank each
+    // time, while the profile only reads off who is switched on.
+    let short = &bank[..10_000];
+    let (_, greedy) = timed(|| (0..=short.len()).map(|k| black_box(max_joltage_digits(short, k)).len()).sum::<usize>());
+    let (_, profiled) = timed(|| {
+        let profile = Profile::new(short);
+        (0..=short.len()).map(|k| black_box(profile.selection(k)).map_or(0, |s| s.digits.len())).sum::<usize>()
+    });
+    println!(
+        "every count of {} batteries: greedy {}, profile {}",
+        short.len(),
+        format_duration(greedy),
+        format_duration(profiled)
+    );
+}
@@ -0,0 +1,443 @@
+use aoc_core::gen::{Generator, Knob, Knobs};
+use aoc_core::parse::{self, ParseError};
+use aoc_core::rng::Rng;
+use aoc_core::{Answer, BigDecimal, Detail, Part, Solution};
+
+pub mod profile;
+pub mod reference;
+pub mod wiring;
+
+pub use profile::Profile;
+pub use wiring::{max_wired_joltage, select_wired, total_wired_joltage, wired_selection_report, Window, Wiring};
+
+pub const NUM_BATTERIES_PART1: usize = 2;
+pub const NUM_BATTERIES_PART2: usize = 12;
+
+/// The batteries switched on in one bank.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Selection {
+    /// Where the chosen batteries sit in the bank, ascending.
+    pub positions: Vec<usize>,
+    /// Their joltage digits, in the same order.
+    pub digits: Vec<u8>,
+    /// The joltage they produce, exactly for any number of batteries.
+    pub value: BigDecimal,
+}
+
+impl Selection {
+    /// The bank with every chosen battery in brackets, as in `81[9]11[2]1`.
+    pub fn mark(&self, bank: &str) -> String {
+        let mut marked = String::with_capacity(bank.len() + 2 * self.positions.len());
+        let mut chosen = self.positions.iter().peekable();
+        for (i, c) in bank.char_indices() {
+            if chosen.next_if_eq(&&i).is_some() {
+                marked.push('[');
+                marked.push(c);
+                marked.push(']');
+            } else {
+                marked.push(c);
+            }
+        }
+        marked
+    }
+}
+
+/// Chooses the `k` batteries of `bank` that, kept in order, make the
+/// largest joltage, or `None` when the bank has fewer.
+///
+/// Uses a monotonic stack in one pass: a digit knocks smaller ones before it
+/// off the stack while there are still `n - k` batteries left to skip, as
+/// moving a larger digit earlier always wins. O(n) for any `k`.
+pub fn select_batteries(bank: &str, k: usize) -> Option<Selection> {
+    let batteries = batteries(bank);
+    if k > batteries.len() {
+        return None;
+    }
+    Some(selection(keep(&batteries, k, |top, digit| top < digit)))
+}
+
+/// The batteries of `bank` as their positions and digits.
+pub(crate) fn batteries(bank: &str) -> Vec<(usize, u8)> {
+    bank.bytes()
+        .enumerate()
+        .filter(|(_, b)| b.is_ascii_digit())
+        .map(|(i, b)| (i, b - b'0'))
+        .collect()
+}
+
+/// Keeps `k` of `batteries` with a monotonic stack, a battery knocking
+/// the top off while `beats(top, digit)` and there are skips left.
+fn keep(batteries: &[(usize, u8)], k: usize, beats: fn(u8, u8) -> bool) -> Vec<(usize, u8)> {
+    let mut skips = batteries.len() - k;
+    let mut stack: Vec<(usize, u8)> = Vec::with_capacity(batteries.len());
+    for &(i, digit) in batteries {
+        while skips > 0 && stack.last().is_some_and(|&(_, top)| beats(top, digit)) {
+            stack.pop();
+            skips -= 1;
+        }
+        stack.push((i, digit));
+    }
+    // Whatever skips are left go on the worst digits, at the end.
+    stack.truncate(k);
+    stack
+}
+
+fn selection(chosen: Vec<(usize, u8)>) -> Selection {
+    let (positions, digits): (Vec<usize>, Vec<u8>) = chosen.into_iter().unzip();
+    let value = BigDecimal::from_digits(&digits, 10);
+    Selection { positions, digits, value }
+}
+
+/// Find the maximum number formed by selecting exactly `k` digits from the bank
+/// while maintaining their relative order, or 0 when the bank has fewer.
+///
+/// `None` once the joltage needs more than a `u64`, which 20 or more
+/// batteries can; [`max_joltage`] is exact for any `k`.
+pub fn max_joltage_from_bank(bank: &str, k: usize) -> Option<u64> {
+    max_joltage(bank, k).to_u128().and_then(|n| u64::try_from(n).ok())
+}
+
+/// The maximum joltage of `k` batteries of `bank`, or 0 when it has fewer.
+pub fn max_joltage(bank: &str, k: usize) -> BigDecimal {
+    select_batteries(bank, k).map_or(BigDecimal::zero(), |selection| selection.value)
+}
+
+/// The maximum joltage of `k` batteries summed over every bank.
+pub fn total_joltage(banks: &[String], k: usize) -> BigDecimal {
+    banks.iter().map(|bank| max_joltage(bank, k)).sum()
+}
+
+/// The digits of the largest joltage from `k` batteries of `bank`, most
+/// significant first, or none when the bank has fewer than `k`.
+pub fn max_joltage_digits(bank: &str, k: usize) -> Vec<u8> {
+    select_batteries(bank, k).map_or(Vec::new(), |selection| selection.digits)
+}
+
+/// Whether a joltage may start with a zero battery, which makes it a
+/// number with fewer digits than batteries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LeadingZeros {
+    #[default]
+    Allowed,
+    Forbidden,
+}
+
+/// Chooses the `k` batteries of `bank` that make the smallest joltage, or
+/// `None` when the bank has fewer, or no non-zero battery to lead with
+/// when leading zeros are forbidden.
+///
+/// The same monotonic stack as [`select_batteries`], knocking off larger
+/// digits instead; without leading zeros the first battery is the earliest
+/// of the smallest non-zero digits that leave room for the rest.
+pub fn select_min_batteries(bank: &str, k: usize, zeros: LeadingZeros) -> Option<Selection> {
+    let batteries = batteries(bank);
+    let n = batteries.len();
+    if k > n {
+        return None;
+    }
+    if k == 0 || zeros == LeadingZeros::Allowed {
+        return Some(selection(keep(&batteries, k, |top, digit| top > digit)));
+    }
+    let (first, &lead) = batteries[..=n - k]
+        .iter()
+        .enumerate()
+        .filter(|(_, &(_, digit))| digit != 0)
+        .min_by_key(|&(i, &(_, digit))| (digit, i))?;
+    let mut chosen = vec![lead];
+    chosen.extend(keep(&batteries[first + 1..], k - 1, |top, digit| top > digit));
+    Some(selection(chosen))
+}
+
+/// The smallest joltage of `k` batteries of `bank`, or `None` as for
+/// [`select_min_batteries`].
+pub fn min_joltage(bank: &str, k: usize, zeros: LeadingZeros) -> Option<BigDecimal> {
+    select_min_batteries(bank, k, zeros).map(|selection| selection.value)
+}
+
+/// Chooses the `k` batteries of `bank` that make the smallest joltage of at
+/// least `threshold`, or `None` when no `k` batteries reach it.
+///
+/// The best selection follows the threshold's digits, padded with zeros to
+/// `k`, for as long as it can, then beats the next digit by as little as
+/// possible and makes the rest as small as it can. Following the threshold
+/// longer always wins, so each shared prefix is tried from the longest the
+/// bank can match, matching every digit at its earliest. O(n + k).
+pub fn select_at_least(bank: &str, k: usize, threshold: &BigDecimal, zeros: LeadingZeros) -> Option<Selection> {
+    let batteries = batteries(bank);
+    let n = batteries.len();
+    let written = threshold.to_string();
+    if k == 0 {
+        return threshold.is_zero().then(|| selection(Vec::new()));
+    }
+    if k > n || written.len() > k {
+        return None;
+    }
+    let target: Vec<u8> = std::iter::repeat_n(0, k - written.len()).chain(written.bytes().map(|b| b - b'0')).collect();
+    // Following a threshold that starts with zero means a leading zero.
+    let may_follow = zeros == LeadingZeros::Allowed || target[0] != 0;
+
+    // The earliest batteries matching each digit of the threshold in turn,
+    // each leaving room for the rest.
+    let mut matched: Vec<usize> = Vec::with_capacity(k);
+    let mut from = 0;
+    while may_follow && matched.len() < k {
+        let j = matched.len();
+        match (from..=n - (k - j)).find(|&i| batteries[i].1 == target[j]) {
+            Some(i) => {
+                matched.push(i);
+                from = i + 1;
+            }
+            None => break,
+        }
+    }
+    let chosen_prefix = |j: usize| -> Vec<(usize, u8)> { matched[..j].iter().map(|&i| batteries[i]).collect() };
+    if matched.len() == k {
+        return Some(selection(chosen_prefix(k)));
+    }
+
+    // Walk the shared prefix down from the longest, keeping the first of
+    // each digit from where the rest of the threshold would start.
+    let mut first = [n; 10];
+    let mut scanned = n;
+    for j in (0..=matched.len()).rev() {
+        let start = if j == 0 { 0 } else { matched[j - 1] + 1 };
+        while scanned > start {
+            scanned -= 1;
+            first[batteries[scanned].1 as usize] = scanned;
+        }
+        let last = n - (k - j);
+        let Some(i) = (target[j] + 1..=9).map(|d| first[d as usize]).find(|&i| i <= last) else {
+            continue;
+        };
+        let mut chosen = chosen_prefix(j);
+        chosen.push(batteries[i]);
+        chosen.extend(keep(&batteries[i + 1..], k - j - 1, |top, digit| top > digit));
+        return Some(selection(chosen));
+    }
+    None
+}
+
+/// Splits the input into one bank of batteries per line, each a run of
+/// joltage digits.
+pub fn parse_banks(input: &str) -> Result<Vec<String>, ParseError> {
+    parse::lines(input)
+        .map(|line| {
+            let bank = line.trim();
+            match bank.find(|c: char| !c.is_ascii_digit()) {
+                Some(i) => {
+                    let c = bank[i..].chars().next().unwrap();
+                    Err(ParseError::at(input, &bank[i..i + c.len_utf8()], "a joltage digit"))
+                }
+                None => Ok(bank.to_string()),
+            }
+        })
+        .collect()
+}
+
+pub fn solve_part1(banks: &[String]) -> BigDecimal {
+    total_joltage(banks, NUM_BATTERIES_PART1)
+}
+
+pub fn solve_part2(banks: &[String]) -> BigDecimal {
+    total_joltage(banks, NUM_BATTERIES_PART2)
+}
+
+/// One line per bank showing which `k` batteries are switched on and the
+/// joltage they make.
+pub fn selection_report(banks: &[String], k: usize) -> String {
+    let selections: Vec<Option<Selection>> = banks.iter().map(|bank| select_batteries(bank, k)).collect();
+    report_selections(banks, &selections, &format!("fewer than {} batteries", k))
+}
+
+/// One report line per bank with its selection, or `missing` in brackets
+/// where it has none.
+pub fn report_selections(banks: &[String], selections: &[Option<Selection>], missing: &str) -> String {
+    let width = banks.len().to_string().len();
+    let mut report = String::new();
+    for (i, (bank, selection)) in banks.iter().zip(selections).enumerate() {
+        let line = match selection {
+            Some(selection) => format!("{:>w$}  {}  {}", i + 1, selection.mark(bank), selection.value, w = width),
+            None => format!("{:>w$}  {}  ({})", i + 1, bank, missing, w = width),
+        };
+        report.push_str(&line);
+        report.push('\n');
+    }
+    report
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    type Parsed = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_banks(input)
+    }
+
+    fn part1(banks: &Self::Parsed) -> Answer {
+        solve_part1(banks).into()
+    }
+
+    fn part2(banks: &Self::Parsed) -> Answer {
+        solve_part2(banks).into()
+    }
+
+    fn details(banks: &Self::Parsed, part: Part) -> Vec<(&'static str, Detail)> {
+        let k = match part {
+            Part::One => NUM_BATTERIES_PART1,
+            Part::Two => NUM_BATTERIES_PART2,
+        };
+        let joltages: Vec<BigDecimal> = banks.iter().map(|bank| max_joltage(bank, k)).collect();
+        // Exact as text once a joltage no longer fits the integer detail.
+        let detail = |joltage: Option<&BigDecimal>| {
+            let joltage = joltage.cloned().unwrap_or_default();
+            match joltage.to_u128().and_then(|n| i64::try_from(n).ok()) {
+                Some(n) => Detail::Int(n),
+                None => Detail::Text(joltage.to_string()),
+            }
+        };
+        vec![
+            ("banks", Detail::Int(banks.len() as i64)),
+            ("lowest_bank_joltage", detail(joltages.iter().min())),
+            ("highest_bank_joltage", detail(joltages.iter().max())),
+        ]
+    }
+}
+
+impl Generator for Day3 {
+    const KNOBS: &'static [Knob] = &[
+        Knob { name: "banks", default: 200, help: "number of battery banks" },
+        Knob { name: "length", default: 100, help: "batteries in each bank" },
+    ];
+
+    fn generate(rng: &mut Rng, knobs: &Knobs) -> String {
+        let mut input = String::new();
+        for _ in 0..knobs.size("banks").max(1) {
+            for _ in 0..knobs.size("length").max(1) {
+                input.push(char::from_digit(rng.range(1..=9) as u32, 10).unwrap());
+            }
+            input.push('\n');
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1_example() {
+        let input = "987654321111111\n811111111111119\n234234234234278\n818181911112111";
+        assert_eq!(solve_part1(&parse_banks(input).unwrap()), BigDecimal::from(357u64));
+    }
+
+    #[test]
+    fn test_part1_individual_banks() {
+        assert_eq!(max_joltage_from_bank("987654321111111", 2), Some(98));
+        assert_eq!(max_joltage_from_bank("811111111111119", 2), Some(89));
+        assert_eq!(max_joltage_from_bank("234234234234278", 2), Some(78));
+        assert_eq!(max_joltage_from_bank("818181911112111", 2), Some(92));
+    }
+
+    #[test]
+    fn test_part2_example() {
+        let input = "987654321111111\n811111111111119\n234234234234278\n818181911112111";
+        assert_eq!(solve_part2(&parse_banks(input).unwrap()), BigDecimal::from(3121910778619u64));
+    }
+
+    #[test]
+    fn test_part2_individual_banks() {
+        assert_eq!(max_joltage_from_bank("987654321111111", 12), Some(987654321111));
+        assert_eq!(max_joltage_from_bank("811111111111119", 12), Some(811111111119));
+        assert_eq!(max_joltage_from_bank("234234234234278", 12), Some(434234234278));
+        assert_eq!(max_joltage_from_bank("818181911112111", 12), Some(888911112111));
+    }
+
+    #[test]
+    fn test_selection() {
+        let selection = select_batteries("818181911112111", 2).unwrap();
+        assert_eq!((selection.positions.as_slice(), selection.digits.as_slice()), (&[6, 11][..], &[9, 2][..]));
+        assert_eq!(selection.value, BigDecimal::from(92u64));
+        assert_eq!(selection.mark("818181911112111"), "818181[9]1111[2]111");
+        let selection = select_batteries("987", 3).unwrap();
+        assert_eq!(selection.mark("987"), "[9][8][7]");
+        assert_eq!(select_batteries("12", 3), None);
+    }
+
+    #[test]
+    fn test_selection_report() {
+        let banks = parse_banks("987654321111111\n811111111111119\n1").unwrap();
+        assert_eq!(
+            selection_report(&banks, 2),
+            "1  [9][8]7654321111111  98\n2  [8]1111111111111[9]  89\n3  1  (fewer than 2 batteries)\n"
+        );
+    }
+
+    #[test]
+    fn test_large_k_is_exact() {
+        let bank = "9".repeat(300) + &"1".repeat(300);
+        assert_eq!(max_joltage(&bank, 500).to_string(), "9".repeat(300) + &"1".repeat(200));
+        let banks = vec!["9".repeat(50); 3];
+        assert_eq!(total_joltage(&banks, 50).to_string(), format!("2{}7", "9".repeat(49)));
+        assert_eq!(max_joltage("12", 50), BigDecimal::zero());
+    }
+
+    #[test]
+    fn test_max_joltage_from_bank_refuses_to_overflow() {
+        assert_eq!(max_joltage_from_bank(&"9".repeat(19), 19), Some(9_999_999_999_999_999_999));
+        assert_eq!(max_joltage_from_bank(&"9".repeat(20), 20), None);
+    }
+
+    #[test]
+    fn test_digits_for_any_k() {
+        assert_eq!(max_joltage_digits("4321", 4), vec![4, 3, 2, 1]);
+        assert_eq!(max_joltage_digits("1234", 2), vec![3, 4]);
+        assert_eq!(max_joltage_digits("9119", 3), vec![9, 1, 9]);
+        assert_eq!(max_joltage_digits("12", 0), vec![]);
+        assert_eq!(max_joltage_digits("12", 3), vec![]);
+        let bank = "1".repeat(500) + &"9".repeat(500);
+        assert_eq!(max_joltage_digits(&bank, 600), [vec![1; 100], vec![9; 500]].concat());
+    }
+
+    #[test]
+    fn test_min_joltage() {
+        assert_eq!(min_joltage("818181911112111", 2, LeadingZeros::Allowed), Some(BigDecimal::from(11u64)));
+        assert_eq!(min_joltage("3021", 2, LeadingZeros::Allowed), Some(BigDecimal::from(1u64)));
+        assert_eq!(min_joltage("3021", 2, LeadingZeros::Forbidden), Some(BigDecimal::from(21u64)));
+        let selection = select_min_batteries("0900", 2, LeadingZeros::Forbidden).unwrap();
+        assert_eq!(selection.mark("0900"), "0[9][0]0");
+        assert_eq!(min_joltage("0009", 2, LeadingZeros::Forbidden), None);
+        assert_eq!(min_joltage("12", 3, LeadingZeros::Allowed), None);
+    }
+
+    #[test]
+    fn test_select_at_least() {
+        let at_least = |bank: &str, k: usize, threshold: u64, zeros: LeadingZeros| {
+            select_at_least(bank, k, &threshold.into(), zeros).map(|selection| selection.mark(bank))
+        };
+        assert_eq!(at_least("818181911112111", 2, 92, LeadingZeros::Allowed).unwrap(), "818181[9]1111[2]111");
+        assert_eq!(at_least("818181911112111", 2, 82, LeadingZeros::Allowed).unwrap(), "[8]1818191111[2]111");
+        assert_eq!(at_least("818181911112111", 2, 90, LeadingZeros::Allowed).unwrap(), "818181[9][1]1112111");
+        assert_eq!(at_least("818181911112111", 2, 93, LeadingZeros::Allowed), None);
+        assert_eq!(at_least("3021", 2, 1, LeadingZeros::Allowed).unwrap(), "3[0]2[1]");
+        assert_eq!(at_least("3021", 2, 1, LeadingZeros::Forbidden).unwrap(), "30[2][1]");
+        assert_eq!(at_least("3021", 0, 0, LeadingZeros::Allowed).unwrap(), "3021");
+        assert_eq!(at_least("3021", 2, 100, LeadingZeros::Allowed), None);
+    }
+
+    #[test]
+    fn test_solution_example() {
+        let input = "987654321111111\n811111111111119\n234234234234278\n818181911112111";
+        let (part1, part2) = aoc_core::solution::solve::<Day3>(input).unwrap();
+        assert_eq!(part1, Answer::Unsigned(357));
+        assert_eq!(part2, Answer::Unsigned(3121910778619));
+    }
+
+    #[test]
+    fn test_parse_banks_rejects_non_digits() {
+        let err = parse_banks("987654321111111\n8111x1111111119").unwrap_err();
+        assert_eq!((err.line, err.column, err.snippet.as_str()), (2, 5, "x"));
+    }
+}
@@ -1,107 +1,233 @@
-use std::fs;
-
-const NUM_BATTERIES_PART1: usize = 2;
-const NUM_BATTERIES_PART2: usize = 12;
-
-/// Find the maximum number formed by selecting exactly `k` digits from the bank
-/// while maintaining their relative order.
-/// 
-/// Uses a greedy approach: at each step, pick the largest digit possible
-/// from the valid range (ensuring enough digits remain for the rest).
-fn max_joltage_from_bank(bank: &str, k: usize) -> u64 {
-    let digits: Vec<u64> = bank.chars()
-        .filter_map(|c| c.to_digit(10))
-        .map(|d| d as u64)
-        .collect();
-    
-    let n = digits.len();
-    if k > n {
-        return 0;
-    }
-    
-    let mut result: u64 = 0;
-    let mut start = 0;
-    
-    // We need to pick k digits. For each position in our result:
-    // - We need to leave enough digits for the remaining positions
-    // - Pick the maximum digit in the valid range
-    for remaining in (1..=k).rev() {
-        // We can pick from start to (n - remaining) inclusive
-        let end = n - remaining;
-        
-        // Find the maximum digit in range [start, end]
-        let mut best_idx = start;
-        let mut best_digit = digits[start];
-        for i in start..=end {
-            if digits[i] > best_digit {
-                best_digit = digits[i];
-                best_idx = i;
-            }
-        }
-        
-        result = result * 10 + best_digit;
-        start = best_idx + 1;
+use aoc_core::cli::{ArgError, Args};
+use aoc_core::Solution;
+use aoc_core::BigDecimal;
+use day_3::{
+    report_selections, select_at_least, select_batteries, select_min_batteries, select_wired, selection_report, Day3,
+    LeadingZeros, Profile, Selection, Window, Wiring, NUM_BATTERIES_PART1, NUM_BATTERIES_PART2,
+};
+use std::collections::BTreeMap;
+use std::process::ExitCode;
+use std::str::FromStr;
+
+const USAGE: &str = "\
+Usage: day-3 [--k LIST] [--report] [--no-adjacent] [--window M/W]
+             [--mandatory POSITIONS] [--forbidden POSITIONS]
+             [--min | --reach JOLTS] [--no-leading-zeros] [INPUT]
+
+Totals the largest joltage of every bank in INPUT (input.txt by default)
+for both parts. --report also lists each bank with its chosen batteries in
+brackets and the joltage they make.
+
+--k prints a table per bank of the largest joltage for every number of
+batteries in LIST, then the totals. LIST holds counts and ranges separated
+by commas, such as 2,12 or 1-20 or 50-; an open range runs to the length
+of each bank. Joltages are exact however many batteries are switched on,
+and --report adds the chosen batteries to every row.
+
+The wiring options limit which batteries may be switched on together, and
+every joltage is then the largest the wiring allows. --no-adjacent keeps
+neighbours from both being on, --window M/W allows at most M on in any W
+neighbouring batteries, and --mandatory and --forbidden take comma-separated
+positions, counting from 0, that must be on or must stay off. A bank with
+no selection that fits adds nothing to the totals.
+
+--min asks for the smallest joltage instead, and --reach JOLTS for the
+smallest that is at least JOLTS, counting the banks that can reach it.
+Both work with --k and --report but not with wiring options. A joltage may
+start with a zero battery unless --no-leading-zeros is given.";
+
+/// The battery counts --k asks for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Counts(Vec<(usize, Option<usize>)>);
+
+impl Counts {
+    /// The counts in order, without repeats, for a bank of `len` batteries.
+    fn for_bank(&self, len: usize) -> Vec<usize> {
+        let mut ks: Vec<usize> = self.0.iter().flat_map(|&(first, last)| first..=last.unwrap_or(len)).collect();
+        ks.sort_unstable();
+        ks.dedup();
+        ks
     }
-    
-    result
 }
 
-fn solve_part1(input: &str) -> u64 {
-    input
-        .lines()
-        .filter(|line| !line.is_empty())
-        .map(|line| max_joltage_from_bank(line, NUM_BATTERIES_PART1))
-        .sum()
+impl FromStr for Counts {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let count = |text: &str| text.trim().parse::<usize>().map_err(|_| format!("invalid battery count '{}'", text));
+        s.split(',')
+            .map(|item| match item.split_once('-') {
+                Some((first, "")) => Ok((count(first)?, None)),
+                Some((first, last)) => match (count(first)?, count(last)?) {
+                    (first, last) if first > last => Err(format!("battery range '{}' runs backwards", item)),
+                    (first, last) => Ok((first, Some(last))),
+                },
+                None => count(item).map(|k| (k, Some(k))),
+            })
+            .collect::<Result<_, _>>()
+            .map(Counts)
+    }
 }
 
-fn solve_part2(input: &str) -> u64 {
-    input
-        .lines()
-        .filter(|line| !line.is_empty())
-        .map(|line| max_joltage_from_bank(line, NUM_BATTERIES_PART2))
-        .sum()
+/// Battery positions given on the command line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Positions(Vec<usize>);
+
+impl FromStr for Positions {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(',')
+            .map(|item| item.trim().parse().map_err(|_| format!("invalid battery position '{}'", item)))
+            .collect::<Result<_, _>>()
+            .map(Positions)
+    }
 }
 
-fn main() {
-    let input = fs::read_to_string("input.txt")
-        .expect("Failed to read input.txt");
-    
-    let part1 = solve_part1(&input);
-    println!("Part 1 - Total output joltage: {}", part1);
-    
-    let part2 = solve_part2(&input);
-    println!("Part 2 - Total output joltage: {}", part2);
+/// What each bank is asked for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Query {
+    /// The largest joltage the wiring allows.
+    Max(Wiring),
+    Min(LeadingZeros),
+    /// The smallest joltage of at least the given one.
+    Reach(BigDecimal, LeadingZeros),
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+impl Query {
+    fn select(&self, bank: &str, k: usize) -> Option<Selection> {
+        match self {
+            Query::Max(wiring) if *wiring == Wiring::default() => select_batteries(bank, k),
+            Query::Max(wiring) => select_wired(bank, k, wiring),
+            Query::Min(zeros) => select_min_batteries(bank, k, *zeros),
+            Query::Reach(jolts, zeros) => select_at_least(bank, k, jolts, *zeros),
+        }
+    }
+
+    /// What a bank without a selection is reported with.
+    fn missing(&self, k: usize) -> String {
+        match self {
+            Query::Max(wiring) if *wiring == Wiring::default() => format!("fewer than {} batteries", k),
+            Query::Max(_) => format!("no {} batteries fit the wiring", k),
+            Query::Min(_) => format!("no joltage of {} batteries", k),
+            Query::Reach(jolts, _) => format!("cannot reach {} jolts", jolts),
+        }
+    }
+
+    /// The line summing up the joltages of `k` batteries over every bank,
+    /// as in `Total output joltage with 12 batteries: 357`.
+    fn summary(&self, k: Option<usize>, joltages: &[Option<BigDecimal>]) -> String {
+        let with = k.map_or(String::new(), |k| format!(" with {} batteries", k));
+        let total = || joltages.iter().flatten().cloned().sum::<BigDecimal>();
+        match self {
+            Query::Max(_) => format!("Total output joltage{}: {}", with, total()),
+            Query::Min(_) => format!("Total minimum joltage{}: {}", with, total()),
+            Query::Reach(jolts, _) => {
+                let reached = joltages.iter().flatten().count();
+                format!("Banks reaching {} jolts{}: {} of {}", jolts, with, reached, joltages.len())
+            }
+        }
+    }
+}
 
-    #[test]
-    fn test_part1_example() {
-        let input = "987654321111111\n811111111111119\n234234234234278\n818181911112111";
-        assert_eq!(solve_part1(input), 357);
+fn main() -> ExitCode {
+    match run(Args::from_env()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}\n\n{}", err, USAGE);
+            ExitCode::FAILURE
+        }
     }
+}
 
-    #[test]
-    fn test_part1_individual_banks() {
-        assert_eq!(max_joltage_from_bank("987654321111111", 2), 98);
-        assert_eq!(max_joltage_from_bank("811111111111119", 2), 89);
-        assert_eq!(max_joltage_from_bank("234234234234278", 2), 78);
-        assert_eq!(max_joltage_from_bank("818181911112111", 2), 92);
+fn run(mut args: Args) -> Result<(), ArgError> {
+    if args.flag("--help") {
+        println!("{}", USAGE);
+        return Ok(());
     }
+    let counts: Option<Counts> = args.parsed("--k")?;
+    let report = args.flag("--report");
+    let wiring = Wiring {
+        no_adjacent: args.flag("--no-adjacent"),
+        window: args.parsed::<Window>("--window")?,
+        mandatory: args.parsed::<Positions>("--mandatory")?.map_or(Vec::new(), |positions| positions.0),
+        forbidden: args.parsed::<Positions>("--forbidden")?.map_or(Vec::new(), |positions| positions.0),
+    };
+    let min = args.flag("--min");
+    let reach: Option<BigDecimal> = args.parsed("--reach")?;
+    let zeros = if args.flag("--no-leading-zeros") { LeadingZeros::Forbidden } else { LeadingZeros::Allowed };
+    let query = match (min, reach) {
+        (false, None) if zeros == LeadingZeros::Forbidden => {
+            return Err(ArgError("--no-leading-zeros needs --min or --reach".to_string()));
+        }
+        (false, None) => Query::Max(wiring),
+        (true, Some(_)) => return Err(ArgError("--min and --reach cannot be combined".to_string())),
+        _ if wiring != Wiring::default() => {
+            return Err(ArgError("--min and --reach do not take wiring options".to_string()));
+        }
+        (true, None) => Query::Min(zeros),
+        (false, Some(jolts)) => Query::Reach(jolts, zeros),
+    };
+    let path = match &args.finish()?[..] {
+        [] => None,
+        [path] => Some(path.clone()),
+        [_, extra, ..] => return Err(ArgError(format!("unexpected argument '{}'", extra))),
+    };
 
-    #[test]
-    fn test_part2_example() {
-        let input = "987654321111111\n811111111111119\n234234234234278\n818181911112111";
-        assert_eq!(solve_part2(input), 3121910778619);
+    let input = aoc_core::input::load_from(env!("CARGO_MANIFEST_DIR"), path);
+    let banks = input.parse::<Day3>();
+
+    if let Some(counts) = counts {
+        print_profiles(&banks, &counts, report, &query);
+        return Ok(());
+    }
+    if query != Query::Max(Wiring::default()) {
+        for (part, k) in [(1, NUM_BATTERIES_PART1), (2, NUM_BATTERIES_PART2)] {
+            let selections: Vec<Option<Selection>> = banks.iter().map(|bank| query.select(bank, k)).collect();
+            if report {
+                print!("{}", report_selections(&banks, &selections, &query.missing(k)));
+            }
+            let joltages: Vec<Option<BigDecimal>> = selections.into_iter().map(|selection| selection.map(|s| s.value)).collect();
+            println!("Part {} - {}", part, query.summary(None, &joltages));
+        }
+        return Ok(());
+    }
+    if report {
+        print!("{}", selection_report(&banks, NUM_BATTERIES_PART1));
     }
+    println!("Part 1 - Total output joltage: {}", Day3::part1(&banks));
+    if report {
+        print!("{}", selection_report(&banks, NUM_BATTERIES_PART2));
+    }
+    println!("Part 2 - Total output joltage: {}", Day3::part2(&banks));
+    Ok(())
+}
 
-    #[test]
-    fn test_part2_individual_banks() {
-        assert_eq!(max_joltage_from_bank("987654321111111", 12), 987654321111);
-        assert_eq!(max_joltage_from_bank("811111111111119", 12), 811111111119);
-        assert_eq!(max_joltage_from_bank("234234234234278", 12), 434234234278);
-        assert_eq!(max_joltage_from_bank("818181911112111", 12), 888911112111);
+/// A table per bank of the answer to `query` for each count, then the
+/// totals. The largest joltages without wiring all come from one
+/// [`Profile`] per bank.
+fn print_profiles(banks: &[String], counts: &Counts, report: bool, query: &Query) {
+    let mut joltages: BTreeMap<usize, Vec<Option<BigDecimal>>> = BTreeMap::new();
+    for (i, bank) in banks.iter().enumerate() {
+        let profile = Profile::new(bank);
+        let ks = counts.for_bank(profile.len());
+        let width = ks.last().map_or(1, |k| k.to_string().len().max(1));
+        println!("Bank {} ({} batteries)", i + 1, profile.len());
+        for k in ks {
+            let selection = match query {
+                Query::Max(wiring) if *wiring == Wiring::default() => profile.selection(k),
+                _ => query.select(bank, k),
+            };
+            let row = match &selection {
+                Some(selection) if report => format!("{}  {}", selection.value, selection.mark(bank)),
+                Some(selection) => selection.value.to_string(),
+                None => "-".to_string(),
+            };
+            println!("  {:>w$}  {}", k, row, w = width);
+            joltages.entry(k).or_default().push(selection.map(|selection| selection.value));
+        }
+    }
+    for (k, joltages) in joltages {
+        println!("{}", query.summary(Some(k), &joltages));
     }
 }
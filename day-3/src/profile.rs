@@ -0,0 +1,110 @@
+use crate::Selection;
+use aoc_core::BigDecimal;
+
+/// The best selection of a bank for every number of batteries at once.
+///
+/// The best `k - 1` batteries are always the best `k` with one dropped: the
+/// first that is smaller than the one after it, or else the last. Dropping
+/// them one at a time from the full bank gives every battery the smallest
+/// `k` it is switched on for, in O(n) overall rather than the O(n²) of a
+/// greedy run per `k`.
+#[derive(Debug, Clone)]
+pub struct Profile {
+    /// Where each battery sits in the bank, and its digit.
+    batteries: Vec<(usize, u8)>,
+    /// Per battery, the fewest batteries that still include it.
+    min_k: Vec<usize>,
+}
+
+impl Profile {
+    pub fn new(bank: &str) -> Profile {
+        let batteries = crate::batteries(bank);
+        let n = batteries.len();
+        let digit = |i: usize| batteries[i].1;
+
+        // A doubly linked list over the batteries still switched on, with
+        // `n` as the end marker both ways.
+        let mut next: Vec<usize> = (1..=n).collect();
+        let mut prev: Vec<usize> = (0..n).map(|i| if i == 0 { n } else { i - 1 }).collect();
+        let mut head = 0;
+        let mut min_k = vec![0; n];
+        // Every battery before `cursor` is at least as large as the next.
+        let mut cursor = 0;
+        for kept in (1..=n).rev() {
+            while next[cursor] != n && digit(cursor) >= digit(next[cursor]) {
+                cursor = next[cursor];
+            }
+            let dropped = cursor;
+            min_k[dropped] = kept;
+            let (before, after) = (prev[dropped], next[dropped]);
+            if before == n {
+                head = after;
+            } else {
+                next[before] = after;
+            }
+            if after != n {
+                prev[after] = before;
+            }
+            // Only the battery before the gap can now be smaller than its
+            // new neighbour.
+            cursor = if before == n { head } else { before };
+        }
+        Profile { batteries, min_k }
+    }
+
+    /// How many batteries the bank has.
+    pub fn len(&self) -> usize {
+        self.batteries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.batteries.is_empty()
+    }
+
+    /// The best `k` batteries, or `None` when the bank has fewer; the same
+    /// as [`select_batteries`](crate::select_batteries) gives.
+    pub fn selection(&self, k: usize) -> Option<Selection> {
+        if k > self.len() {
+            return None;
+        }
+        let (positions, digits): (Vec<usize>, Vec<u8>) = self
+            .batteries
+            .iter()
+            .zip(&self.min_k)
+            .filter(|&(_, &min_k)| min_k <= k)
+            .map(|(&battery, _)| battery)
+            .unzip();
+        let value = BigDecimal::from_digits(&digits, 10);
+        Some(Selection { positions, digits, value })
+    }
+
+    /// The largest joltage of `k` batteries, or 0 when the bank has fewer.
+    pub fn joltage(&self, k: usize) -> BigDecimal {
+        self.selection(k).map_or(BigDecimal::zero(), |selection| selection.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::select_batteries;
+
+    #[test]
+    fn test_profile_matches_greedy_for_every_k() {
+        for bank in ["987654321111111", "811111111111119", "234234234234278", "818181911112111", "1", "", "3303122"] {
+            let profile = Profile::new(bank);
+            for k in 0..=bank.len() + 1 {
+                assert_eq!(profile.selection(k), select_batteries(bank, k), "{} with k = {}", bank, k);
+            }
+        }
+    }
+
+    #[test]
+    fn test_joltages() {
+        let profile = Profile::new("818181911112111");
+        let joltages: Vec<String> = (1..=4).map(|k| profile.joltage(k).to_string()).collect();
+        assert_eq!(joltages, ["9", "92", "921", "9211"]);
+        assert_eq!(profile.joltage(12).to_string(), "888911112111");
+        assert_eq!(profile.joltage(16), BigDecimal::zero());
+    }
+}
@@ -0,0 +1,351 @@
+//! Slow but obviously correct references for the joltage selection, used
+//! to check the monotonic stack on small random banks by brute force and
+//! on long ones against the window scan it replaced.
+
+use std::cmp::Reverse;
+use aoc_core::oracle::{Check, Oracle};
+use aoc_core::rng::Rng;
+use aoc_core::BigDecimal;
+use crate::{
+    max_joltage_digits, select_at_least, select_batteries, select_min_batteries, select_wired,
+    LeadingZeros, Profile, Selection, Window, Wiring,
+};
+
+/// The largest value of any `k` batteries of `bank` kept in order, found by
+/// trying every subset of that size.
+pub fn max_joltage(bank: &str, k: usize) -> u64 {
+    let digits: Vec<u64> = bank.bytes().map(|b| (b - b'0') as u64).collect();
+    assert!(digits.len() < 32, "bank too long to enumerate");
+    (0u32..1 << digits.len())
+        .filter(|mask| mask.count_ones() as usize == k)
+        .map(|mask| {
+            (0..digits.len())
+                .filter(|&i| mask >> i & 1 == 1)
+                .fold(0, |value, i| value * 10 + digits[i])
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+/// The digits of the largest joltage from `k` batteries of `bank`, picking
+/// each in turn as the largest digit that still leaves enough batteries
+/// after it for the rest. O(n·k).
+pub fn max_joltage_by_window_scan(bank: &str, k: usize) -> Vec<u8> {
+    let digits: Vec<u8> = bank.bytes().map(|b| b - b'0').collect();
+    if k > digits.len() {
+        return Vec::new();
+    }
+    let mut picked = Vec::with_capacity(k);
+    let mut start = 0;
+    for remaining in (1..=k).rev() {
+        let window = &digits[start..=digits.len() - remaining];
+        // The first of the largest digits leaves the most choice afterwards.
+        let best = *window.iter().max().unwrap();
+        start += window.iter().position(|&d| d == best).unwrap() + 1;
+        picked.push(best);
+    }
+    picked
+}
+
+/// The selection of `k` batteries of `bank` with the largest joltage the
+/// wiring allows, and of those the earliest positions, found by trying
+/// every subset of that size.
+pub fn max_wired_selection(bank: &str, k: usize, wiring: &Wiring) -> Option<Selection> {
+    let digits: Vec<u8> = bank.bytes().map(|b| b - b'0').collect();
+    assert!(digits.len() < 32, "bank too long to enumerate");
+    let (digits, Reverse(positions)) = (0u32..1 << digits.len())
+        .filter(|mask| mask.count_ones() as usize == k)
+        .map(|mask| (0..digits.len()).filter(|&i| mask >> i & 1 == 1).collect::<Vec<usize>>())
+        .filter(|positions| wiring.allows(digits.len(), positions))
+        .map(|positions| (positions.iter().map(|&i| digits[i]).collect::<Vec<u8>>(), Reverse(positions)))
+        .max()?;
+    let value = BigDecimal::from_digits(&digits, 10);
+    Some(Selection { positions, digits, value })
+}
+
+/// The digits of every selection of `k` batteries of `bank` with the
+/// joltage it makes, leaving out those that lead with a zero when they are
+/// forbidden.
+fn every_selection(bank: &str, k: usize, zeros: LeadingZeros) -> Vec<(Vec<u8>, u64)> {
+    let digits: Vec<u8> = bank.bytes().map(|b| b - b'0').collect();
+    assert!(digits.len() < 20, "bank too long to enumerate");
+    (0u32..1 << digits.len())
+        .filter(|mask| mask.count_ones() as usize == k)
+        .map(|mask| (0..digits.len()).filter(|&i| mask >> i & 1 == 1).map(|i| digits[i]).collect::<Vec<u8>>())
+        .filter(|chosen| zeros == LeadingZeros::Allowed || chosen.first() != Some(&0))
+        .map(|chosen| {
+            let value = chosen.iter().fold(0, |value, &d| value * 10 + d as u64);
+            (chosen, value)
+        })
+        .collect()
+}
+
+/// The digits of the smallest joltage of `k` batteries of `bank`, found by
+/// trying every subset of that size.
+pub fn min_joltage(bank: &str, k: usize, zeros: LeadingZeros) -> Option<Vec<u8>> {
+    every_selection(bank, k, zeros).into_iter().min_by_key(|(_, value)| *value).map(|(digits, _)| digits)
+}
+
+/// The digits of the smallest joltage of `k` batteries of `bank` that is at
+/// least `threshold`, found by trying every subset of that size.
+pub fn at_least(bank: &str, k: usize, threshold: u64, zeros: LeadingZeros) -> Option<Vec<u8>> {
+    every_selection(bank, k, zeros)
+        .into_iter()
+        .filter(|&(_, value)| value >= threshold)
+        .min_by_key(|(_, value)| *value)
+        .map(|(digits, _)| digits)
+}
+
+fn shrink((bank, k): &(String, usize)) -> Vec<(String, usize)> {
+    let mut candidates = Vec::new();
+    for i in 0..bank.len() {
+        if bank.len() > *k {
+            let mut shorter = bank.clone();
+            shorter.remove(i);
+            candidates.push((shorter, *k));
+        }
+    }
+    if *k > 1 {
+        candidates.push((bank.clone(), k - 1));
+    }
+    for (i, digit) in bank.bytes().enumerate() {
+        if digit > b'1' {
+            let mut lower = bank.clone().into_bytes();
+            lower[i] = digit - 1;
+            candidates.push((String::from_utf8(lower).unwrap(), *k));
+        }
+    }
+    candidates
+}
+
+fn random_bank(rng: &mut Rng, len: usize) -> String {
+    // Few distinct digits make for many ties.
+    let top = rng.range(2..=9) as u32;
+    (0..len).map(|_| char::from_digit(rng.range(1..=top as u64) as u32, 10).unwrap()).collect()
+}
+
+/// A bank with zero batteries among the others.
+fn random_bank_with_zeros(rng: &mut Rng, len: usize) -> String {
+    let top = rng.range(1..=9) as u32;
+    (0..len).map(|_| char::from_digit(rng.range(0..=top as u64) as u32, 10).unwrap()).collect()
+}
+
+fn random_zeros(rng: &mut Rng) -> LeadingZeros {
+    if rng.range(0..=1) == 1 {
+        LeadingZeros::Forbidden
+    } else {
+        LeadingZeros::Allowed
+    }
+}
+
+fn random_wiring(rng: &mut Rng, len: usize) -> Wiring {
+    let positions = |rng: &mut Rng| -> Vec<usize> {
+        let mut positions: Vec<usize> = (0..rng.range(0..=2)).map(|_| rng.range(0..=len as u64 - 1) as usize).collect();
+        positions.sort_unstable();
+        positions.dedup();
+        positions
+    };
+    Wiring {
+        no_adjacent: rng.range(0..=1) == 1,
+        window: (rng.range(0..=1) == 1).then(|| Window::new(rng.range(0..=4) as usize, rng.range(1..=8) as usize).unwrap()),
+        mandatory: positions(rng),
+        forbidden: positions(rng),
+    }
+}
+
+fn shrink_wired((bank, k, wiring): &(String, usize, Wiring)) -> Vec<(String, usize, Wiring)> {
+    let mut candidates = Vec::new();
+    if *k > 0 {
+        candidates.push((bank.clone(), k - 1, wiring.clone()));
+    }
+    let mut simpler = Vec::new();
+    if wiring.no_adjacent {
+        simpler.push(Wiring { no_adjacent: false, ..wiring.clone() });
+    }
+    if wiring.window.is_some() {
+        simpler.push(Wiring { window: None, ..wiring.clone() });
+    }
+    for i in 0..wiring.mandatory.len() {
+        let mut fewer = wiring.clone();
+        fewer.mandatory.remove(i);
+        simpler.push(fewer);
+    }
+    for i in 0..wiring.forbidden.len() {
+        let mut fewer = wiring.clone();
+        fewer.forbidden.remove(i);
+        simpler.push(fewer);
+    }
+    candidates.extend(simpler.into_iter().map(|simpler| (bank.clone(), *k, simpler)));
+    // Dropping the last battery keeps every position meaning the same.
+    let last = bank.len().saturating_sub(1);
+    if !bank.is_empty() && !wiring.mandatory.contains(&last) && !wiring.forbidden.contains(&last) {
+        candidates.push((bank[..last].to_string(), *k, wiring.clone()));
+    }
+    for (i, digit) in bank.bytes().enumerate() {
+        if digit > b'1' {
+            let mut lower = bank.clone().into_bytes();
+            lower[i] = digit - 1;
+            candidates.push((String::from_utf8(lower).unwrap(), *k, wiring.clone()));
+        }
+    }
+    candidates
+}
+
+pub fn oracles() -> Vec<Box<dyn Check>> {
+    vec![
+        Box::new(Oracle {
+            name: "largest joltage of a bank",
+            generate: |rng| {
+                let len = rng.range(1..=14) as usize;
+                let bank: String = (0..len)
+                    .map(|_| char::from_digit(rng.range(1..=9) as u32, 10).unwrap())
+                    .collect();
+                (bank, rng.range(1..=len as u64) as usize)
+            },
+            shrink,
+            render: |(bank, k)| format!("{} (k = {})", bank, k),
+            fast: |(bank, k)| crate::max_joltage(bank, *k),
+            reference: |(bank, k)| BigDecimal::from(max_joltage(bank, *k)),
+        }),
+        Box::new(Oracle {
+            name: "joltage digits of a long bank",
+            generate: |rng| {
+                let len = rng.range(1..=300) as usize;
+                (random_bank(rng, len), rng.range(0..=len as u64) as usize)
+            },
+            shrink,
+            render: |(bank, k)| format!("{} (k = {})", bank, k),
+            fast: |(bank, k)| max_joltage_digits(bank, *k),
+            reference: |(bank, k)| max_joltage_by_window_scan(bank, *k),
+        }),
+        Box::new(Oracle {
+            name: "joltage profile of a bank",
+            generate: |rng| {
+                let len = rng.range(0..=60) as usize;
+                random_bank(rng, len)
+            },
+            shrink: |bank| {
+                (0..bank.len())
+                    .map(|i| {
+                        let mut shorter = bank.clone();
+                        shorter.remove(i);
+                        shorter
+                    })
+                    .collect()
+            },
+            render: |bank| bank.clone(),
+            fast: |bank| {
+                let profile = Profile::new(bank);
+                (0..=bank.len()).map(|k| profile.selection(k)).collect::<Vec<_>>()
+            },
+            reference: |bank| (0..=bank.len()).map(|k| select_batteries(bank, k)).collect(),
+        }),
+        Box::new(Oracle {
+            name: "wired joltage of a short bank",
+            generate: |rng| {
+                let len = rng.range(1..=12) as usize;
+                let bank = random_bank(rng, len);
+                let k = rng.range(0..=len as u64) as usize;
+                (bank, k, random_wiring(rng, len))
+            },
+            shrink: shrink_wired,
+            render: |(bank, k, wiring)| format!("{} (k = {}, {:?})", bank, k, wiring),
+            fast: |(bank, k, wiring)| {
+                let selection = select_wired(bank, *k, wiring);
+                if let Some(selection) = &selection {
+                    assert!(wiring.allows(bank.len(), &selection.positions), "{:?} breaks the wiring", selection.positions);
+                }
+                selection
+            },
+            reference: |(bank, k, wiring)| max_wired_selection(bank, *k, wiring),
+        }),
+        Box::new(Oracle {
+            name: "smallest joltage of a bank",
+            generate: |rng| {
+                let len = rng.range(0..=12) as usize;
+                let bank = random_bank_with_zeros(rng, len);
+                (bank, rng.range(0..=len as u64) as usize, random_zeros(rng))
+            },
+            shrink: |(bank, k, zeros)| {
+                let mut candidates: Vec<_> = shrink(&(bank.clone(), *k)).into_iter().map(|(bank, k)| (bank, k, *zeros)).collect();
+                if *zeros == LeadingZeros::Forbidden {
+                    candidates.push((bank.clone(), *k, LeadingZeros::Allowed));
+                }
+                candidates
+            },
+            render: |(bank, k, zeros)| format!("{} (k = {}, leading zeros {:?})", bank, k, zeros),
+            fast: |(bank, k, zeros)| select_min_batteries(bank, *k, *zeros).map(|selection| selection.digits),
+            reference: |(bank, k, zeros)| min_joltage(bank, *k, *zeros),
+        }),
+        Box::new(Oracle {
+            name: "joltage reaching a threshold",
+            generate: |rng| {
+                let len = rng.range(0..=12) as usize;
+                let bank = random_bank_with_zeros(rng, len);
+                let k = rng.range(0..=len as u64) as usize;
+                // Near some selection's joltage, to hit exact matches and
+                // near misses as well as thresholds no selection reaches.
+                let near = bank.bytes().take(k).fold(0, |value, b| value * 10 + (b - b'0') as u64);
+                let threshold = match rng.range(0..=2) {
+                    0 => near,
+                    1 => near + rng.range(0..=20),
+                    _ => rng.range(0..=10u64.pow(k as u32)),
+                };
+                (bank, k, threshold, random_zeros(rng))
+            },
+            shrink: |(bank, k, threshold, zeros)| {
+                let mut candidates: Vec<_> = shrink(&(bank.clone(), *k))
+                    .into_iter()
+                    .map(|(bank, k)| (bank, k, *threshold, *zeros))
+                    .collect();
+                if *threshold > 0 {
+                    candidates.extend([threshold / 10, threshold - 1].map(|threshold| (bank.clone(), *k, threshold, *zeros)));
+                }
+                candidates
+            },
+            render: |(bank, k, threshold, zeros)| format!("{} (k = {}, at least {}, leading zeros {:?})", bank, k, threshold, zeros),
+            fast: |(bank, k, threshold, zeros)| {
+                select_at_least(bank, *k, &(*threshold).into(), *zeros).map(|selection| selection.digits)
+            },
+            reference: |(bank, k, threshold, zeros)| at_least(bank, *k, *threshold, *zeros),
+        }),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_max_joltage() {
+        assert_eq!(max_joltage("818181911112111", 2), 92);
+        assert_eq!(max_joltage("234234234234278", 12), 434234234278);
+        assert_eq!(max_joltage("12", 3), 0);
+    }
+
+    #[test]
+    fn test_wired_positions_match_reference() {
+        let wirings = [
+            Wiring::default(),
+            Wiring { no_adjacent: true, ..Wiring::default() },
+            Wiring { window: Window::new(2, 4), mandatory: vec![5], ..Wiring::default() },
+            Wiring { mandatory: vec![3], forbidden: vec![0, 6], ..Wiring::default() },
+        ];
+        for bank in ["1111112", "99999999", "818181911", "3303122"] {
+            for wiring in &wirings {
+                for k in 0..=bank.len() {
+                    assert_eq!(select_wired(bank, k, wiring), max_wired_selection(bank, k, wiring), "{} with k = {}, {:?}", bank, k, wiring);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_fast_matches_reference() {
+        for check in oracles() {
+            if let Err(mismatch) = check.run(0, 300) {
+                panic!("{}: {}", check.name(), mismatch);
+            }
+        }
+    }
+}
@@ -0,0 +1,341 @@
+//! Battery selection under wiring constraints: no two neighbours switched
+//! on, a cap on how many batteries any run of the bank may have on, and
+//! positions that must or must not be used.
+
+use crate::Selection;
+use aoc_core::BigDecimal;
+use std::fmt;
+use std::str::FromStr;
+
+/// At most `picks` batteries switched on in any `width` neighbouring ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Window {
+    pub picks: usize,
+    pub width: usize,
+}
+
+impl Window {
+    /// A window of `width` batteries, or `None` when it is empty.
+    pub fn new(picks: usize, width: usize) -> Option<Window> {
+        (width > 0).then_some(Window { picks, width })
+    }
+}
+
+impl fmt::Display for Window {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.picks, self.width)
+    }
+}
+
+impl FromStr for Window {
+    type Err = String;
+
+    /// Parses `M/W`, at most `M` batteries in any `W`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let expected = || format!("invalid window '{}', expected M/W with W at least 1", s);
+        let (picks, width) = s.split_once('/').ok_or_else(expected)?;
+        let picks = picks.parse().map_err(|_| expected())?;
+        let width = width.parse().map_err(|_| expected())?;
+        Window::new(picks, width).ok_or_else(expected)
+    }
+}
+
+/// The constraints a bank is wired with. Positions count batteries from 0,
+/// as in [`Selection::positions`]; the default allows every selection.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Wiring {
+    /// No two neighbouring batteries both switched on.
+    pub no_adjacent: bool,
+    pub window: Option<Window>,
+    /// Batteries that must be switched on.
+    pub mandatory: Vec<usize>,
+    /// Batteries that must stay off.
+    pub forbidden: Vec<usize>,
+}
+
+impl Wiring {
+    /// Whether switching on the batteries at `positions`, ascending, in a
+    /// bank of `len` respects the wiring.
+    pub fn allows(&self, len: usize, positions: &[usize]) -> bool {
+        let on = |i: usize| positions.binary_search(&i).is_ok();
+        positions.iter().all(|&i| i < len && !self.forbidden.contains(&i))
+            && self.mandatory.iter().all(|&i| on(i))
+            && !(self.no_adjacent && positions.windows(2).any(|pair| pair[1] == pair[0] + 1))
+            && self.window.is_none_or(|window| {
+                positions
+                    .iter()
+                    .enumerate()
+                    .all(|(first, &start)| positions[first..].iter().take_while(|&&i| i < start + window.width).count() <= window.picks)
+            })
+    }
+
+    /// The limits on the recent past as `(picks, width)`, adjacency being at
+    /// most one in any two.
+    fn limits(&self) -> Vec<(usize, usize)> {
+        let adjacent = self.no_adjacent.then_some((1, 2));
+        let window = self.window.map(|window| (window.picks, window.width));
+        adjacent.into_iter().chain(window).collect()
+    }
+}
+
+/// Chooses the `k` batteries of `bank` that make the largest joltage the
+/// wiring allows, or `None` when no `k` batteries fit it. Of several such
+/// selections, the one with the earliest positions is returned.
+///
+/// Only the last `M` batteries switched on limit what may follow, and no
+/// window limits anything once `M` is at least `k`. How many more batteries
+/// a partial selection can take is counted greedily: switching each on as
+/// early as the wiring and the mandatory batteries still to come allow is
+/// never worse. Digits are then picked largest first. Of the batteries with
+/// that digit, only the first that fits before the next mandatory battery,
+/// and that mandatory battery itself, can lead to the best selection. A
+/// partial selection is dropped when another has passed the same mandatory
+/// batteries and has none of its last `M` positions later. Without
+/// mandatory batteries that leaves a single partial selection, for
+/// O(k·n·M) overall; each mandatory battery can keep one more.
+pub fn select_wired(bank: &str, k: usize, wiring: &Wiring) -> Option<Selection> {
+    let batteries = crate::batteries(bank);
+    let n = batteries.len();
+    let mut mandatory = vec![false; n];
+    for &i in &wiring.mandatory {
+        *mandatory.get_mut(i)? = true;
+    }
+    let mut forbidden = vec![false; n];
+    for &i in &wiring.forbidden {
+        if let Some(forbidden) = forbidden.get_mut(i) {
+            *forbidden = true;
+        }
+    }
+    let mut mandatory_after = vec![0; n + 1];
+    for i in (0..n).rev() {
+        mandatory_after[i] = mandatory_after[i + 1] + mandatory[i] as usize;
+    }
+    let limits: Vec<(usize, usize)> = wiring.limits().into_iter().filter(|&(picks, _)| picks < k).collect();
+    let solver = Solver {
+        digits: batteries.iter().map(|&(_, digit)| digit).collect(),
+        memory: limits.iter().map(|&(picks, _)| picks).max().unwrap_or(0).max(1),
+        upcoming: (0..n).filter(|&i| mandatory[i]).collect(),
+        mandatory,
+        forbidden,
+        mandatory_after,
+        limits,
+    };
+    if !solver.room(&[], k) {
+        return None;
+    }
+
+    // Every partial selection still in the running makes the same, largest
+    // digits so far.
+    let mut frontier: Vec<Vec<usize>> = vec![Vec::new()];
+    for remaining in (0..k).rev() {
+        let mut extended = Vec::new();
+        for digit in (0..=9).rev() {
+            for path in &frontier {
+                let after = path.last().map_or(0, |&i| i + 1);
+                let next_mandatory = solver.next_mandatory(after);
+                let first = (after..next_mandatory).find(|&i| solver.digits[i] == digit && solver.allowed(path, i));
+                let at_mandatory = (next_mandatory < n && solver.digits[next_mandatory] == digit)
+                    .then_some(next_mandatory)
+                    .filter(|&i| solver.allowed(path, i));
+                for i in first.into_iter().chain(at_mandatory) {
+                    let mut longer = path.clone();
+                    longer.push(i);
+                    if solver.room(&longer, remaining) {
+                        extended.push(longer);
+                    }
+                }
+            }
+            if !extended.is_empty() {
+                break;
+            }
+        }
+        frontier = solver.prune(extended);
+    }
+
+    let positions = frontier.into_iter().min()?;
+    let (positions, digits): (Vec<usize>, Vec<u8>) = positions.into_iter().map(|i| batteries[i]).unzip();
+    let value = BigDecimal::from_digits(&digits, 10);
+    Some(Selection { positions, digits, value })
+}
+
+/// A bank and its wiring, indexed by battery.
+struct Solver {
+    digits: Vec<u8>,
+    mandatory: Vec<bool>,
+    forbidden: Vec<bool>,
+    /// The mandatory batteries in order.
+    upcoming: Vec<usize>,
+    /// How many mandatory batteries there are from each position on.
+    mandatory_after: Vec<usize>,
+    /// The window limits as `(picks, width)` that can bind with `k` batteries.
+    limits: Vec<(usize, usize)>,
+    /// How many of the last batteries switched on the limits look back over.
+    memory: usize,
+}
+
+impl Solver {
+    /// Whether batteries switched on at `positions`, ascending, break no
+    /// window limit.
+    fn fits(&self, positions: &[usize]) -> bool {
+        self.limits
+            .iter()
+            .all(|&(picks, width)| positions.windows(picks + 1).all(|run| run[picks] - run[0] >= width))
+    }
+
+    /// The last positions of `path` that can still limit what follows.
+    fn tail<'a>(&self, path: &'a [usize]) -> &'a [usize] {
+        &path[path.len().saturating_sub(self.memory)..]
+    }
+
+    /// Whether battery `i` can be switched on after `path`.
+    fn allowed(&self, path: &[usize], i: usize) -> bool {
+        !self.forbidden[i] && self.fits(&[self.tail(path), &[i]].concat())
+    }
+
+    /// The first mandatory battery from `from` on, or the bank's length.
+    fn next_mandatory(&self, from: usize) -> usize {
+        let next = self.upcoming.partition_point(|&i| i < from);
+        self.upcoming.get(next).copied().unwrap_or(self.digits.len())
+    }
+
+    /// The most batteries that can be switched on after `path`, or `None`
+    /// when some mandatory battery cannot be.
+    fn most_after(&self, path: &[usize]) -> Option<usize> {
+        let mut recent = self.tail(path).to_vec();
+        let mut count = 0;
+        for i in path.last().map_or(0, |&i| i + 1)..self.digits.len() {
+            if !self.allowed(&recent, i) {
+                if self.mandatory[i] {
+                    return None;
+                }
+                continue;
+            }
+            if !self.mandatory[i] {
+                // Only the next few mandatory batteries can be crowded out.
+                let next = self.upcoming.partition_point(|&m| m <= i);
+                let ahead = &self.upcoming[next..(next + self.memory).min(self.upcoming.len())];
+                if !self.fits(&[self.tail(&recent), &[i], ahead].concat()) {
+                    continue;
+                }
+            }
+            recent.push(i);
+            count += 1;
+        }
+        Some(count)
+    }
+
+    /// Whether exactly `remaining` more batteries can follow `path`. Any
+    /// count between the mandatory batteries left and the most can, since
+    /// switching a battery off never breaks the wiring.
+    fn room(&self, path: &[usize], remaining: usize) -> bool {
+        let after = path.last().map_or(0, |&i| i + 1);
+        self.most_after(path).is_some_and(|most| (self.mandatory_after[after]..=most).contains(&remaining))
+    }
+
+    /// Drops the partial selections another does at least as well as: one
+    /// that has passed the same mandatory batteries, is no later at any of
+    /// the last positions that matter, and is no later in order.
+    fn prune(&self, mut paths: Vec<Vec<usize>>) -> Vec<Vec<usize>> {
+        paths.sort_unstable();
+        paths.dedup();
+        let passed = |path: &[usize]| self.upcoming.partition_point(|&i| i <= *path.last().unwrap());
+        let mut kept: Vec<Vec<usize>> = Vec::new();
+        for path in paths {
+            let beaten = kept.iter().any(|other| {
+                passed(other) == passed(&path) && self.tail(other).iter().zip(self.tail(&path)).all(|(a, b)| a <= b)
+            });
+            if !beaten {
+                kept.push(path);
+            }
+        }
+        kept
+    }
+}
+
+/// The largest joltage of `k` batteries of `bank` the wiring allows, or 0
+/// when none does.
+pub fn max_wired_joltage(bank: &str, k: usize, wiring: &Wiring) -> BigDecimal {
+    select_wired(bank, k, wiring).map_or(BigDecimal::zero(), |selection| selection.value)
+}
+
+/// The largest wired joltage of `k` batteries summed over every bank.
+pub fn total_wired_joltage(banks: &[String], k: usize, wiring: &Wiring) -> BigDecimal {
+    banks.iter().map(|bank| max_wired_joltage(bank, k, wiring)).sum()
+}
+
+/// Like [`selection_report`](crate::selection_report), with the batteries
+/// the wiring allows.
+pub fn wired_selection_report(banks: &[String], k: usize, wiring: &Wiring) -> String {
+    let selections: Vec<Option<Selection>> = banks.iter().map(|bank| select_wired(bank, k, wiring)).collect();
+    crate::report_selections(banks, &selections, &format!("no {} batteries fit the wiring", k))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::select_batteries;
+
+    fn wired(bank: &str, k: usize, wiring: &Wiring) -> Option<String> {
+        select_wired(bank, k, wiring).map(|selection| selection.mark(bank))
+    }
+
+    #[test]
+    fn test_unconstrained_matches_greedy() {
+        for bank in ["987654321111111", "811111111111119", "234234234234278", "818181911112111"] {
+            for k in 0..=bank.len() + 1 {
+                let greedy = select_batteries(bank, k).map(|selection| selection.digits);
+                let wired = select_wired(bank, k, &Wiring::default()).map(|selection| selection.digits);
+                assert_eq!(wired, greedy, "{} with k = {}", bank, k);
+            }
+        }
+    }
+
+    #[test]
+    fn test_each_mode() {
+        let no_adjacent = Wiring { no_adjacent: true, ..Wiring::default() };
+        assert_eq!(wired("987654321111111", 3, &no_adjacent).unwrap(), "[9]8[7]6[5]4321111111");
+        assert_eq!(wired("99", 2, &no_adjacent), None);
+
+        let window = Wiring { window: Window::new(2, 4), ..Wiring::default() };
+        assert_eq!(wired("99999999", 4, &window).unwrap(), "[9][9]99[9][9]99");
+        assert_eq!(wired("9999999", 4, &window).unwrap(), "[9][9]99[9][9]9");
+        assert_eq!(wired("999999", 4, &window).unwrap(), "[9][9]99[9][9]");
+        assert_eq!(wired("99999", 4, &window), None);
+
+        let mandatory = Wiring { mandatory: vec![0, 14], ..Wiring::default() };
+        assert_eq!(wired("818181911112111", 3, &mandatory).unwrap(), "[8]18181[9]1111211[1]");
+        assert_eq!(wired("12", 1, &mandatory), None);
+
+        let forbidden = Wiring { forbidden: vec![6], ..Wiring::default() };
+        assert_eq!(wired("818181911112111", 2, &forbidden).unwrap(), "[8]1[8]181911112111");
+    }
+
+    #[test]
+    fn test_modes_combine() {
+        let wiring = Wiring {
+            no_adjacent: true,
+            window: Window::new(2, 5),
+            mandatory: vec![1],
+            forbidden: vec![4],
+        };
+        let selection = select_wired("19999999", 3, &wiring).unwrap();
+        assert!(wiring.allows(8, &selection.positions));
+        assert_eq!(selection.mark("19999999"), "1[9]9[9]99[9]9");
+    }
+
+    #[test]
+    fn test_report() {
+        let banks = vec!["987".to_string(), "99".to_string()];
+        let wiring = Wiring { no_adjacent: true, ..Wiring::default() };
+        assert_eq!(wired_selection_report(&banks, 2, &wiring), "1  [9]8[7]  97\n2  99  (no 2 batteries fit the wiring)\n");
+        assert_eq!(total_wired_joltage(&banks, 2, &wiring), BigDecimal::from(97u64));
+    }
+
+    #[test]
+    fn test_window_parsing() {
+        assert_eq!("2/5".parse(), Ok(Window { picks: 2, width: 5 }));
+        assert!("2/0".parse::<Window>().is_err());
+        assert_eq!("30/64".parse(), Ok(Window { picks: 30, width: 64 }));
+        assert!("2".parse::<Window>().is_err());
+    }
+}
@@ -0,0 +1,101 @@
+use aoc_core::gen::{Generator, Knob, Knobs};
+use aoc_core::grid::{Grid, Pos};
+use aoc_core::parse::ParseError;
+use aoc_core::rng::Rng;
+use aoc_core::{Answer, Solution};
+
+/// The floor plan, `true` where a roll of paper sits.
+pub type Floor = Grid<bool>;
+
+pub fn parse_floor(input: &str) -> Result<Floor, ParseError> {
+    Grid::parse(
+        input,
+        |c| match c {
+            '@' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        },
+        "a roll `@` or empty floor `.`",
+    )
+}
+
+fn count_adjacent_rolls(grid: &Floor, pos: Pos) -> usize {
+    grid.neighbours8(pos).filter(|&p| grid[p]).count()
+}
+
+pub fn find_accessible_rolls(grid: &Floor) -> Vec<Pos> {
+    grid.positions()
+        .filter(|&pos| grid[pos] && count_adjacent_rolls(grid, pos) < 4)
+        .collect()
+}
+
+/// Keeps removing accessible rolls until none remain accessible, returning
+/// how many were removed in total.
+pub fn remove_accessible_rolls(grid: &mut Floor) -> usize {
+    let mut total_removed = 0;
+
+    loop {
+        let accessible = find_accessible_rolls(grid);
+        if accessible.is_empty() {
+            break;
+        }
+
+        // Remove all currently accessible rolls
+        for &pos in &accessible {
+            grid[pos] = false;
+        }
+        total_removed += accessible.len();
+    }
+
+    total_removed
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    type Parsed = Floor;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_floor(input)
+    }
+
+    // Part 1: Count initially accessible rolls
+    fn part1(grid: &Self::Parsed) -> Answer {
+        find_accessible_rolls(grid).len().into()
+    }
+
+    // Part 2: Keep removing accessible rolls until none remain accessible
+    fn part2(grid: &Self::Parsed) -> Answer {
+        remove_accessible_rolls(&mut grid.clone()).into()
+    }
+}
+
+impl Generator for Day4 {
+    const KNOBS: &'static [Knob] = &[
+        Knob { name: "rows", default: 140, help: "rows of the floor plan" },
+        Knob { name: "cols", default: 140, help: "columns of the floor plan" },
+        Knob { name: "density", default: 60, help: "percentage of cells holding a roll" },
+    ];
+
+    fn generate(rng: &mut Rng, knobs: &Knobs) -> String {
+        let mut floor = Grid::new(knobs.size("rows").max(1), knobs.size("cols").max(1), false);
+        for pos in floor.positions().collect::<Vec<_>>() {
+            floor[pos] = rng.chance(knobs.get("density"));
+        }
+        floor.render(|&roll| if roll { '@' } else { '.' })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_removal_peels_a_block_from_the_corners_in() {
+        let mut floor = parse_floor("@@@\n@@@\n@@@\n").unwrap();
+        assert_eq!(find_accessible_rolls(&floor), vec![(0, 0), (0, 2), (2, 0), (2, 2)]);
+        assert_eq!(remove_accessible_rolls(&mut floor), 9);
+        assert_eq!(floor.render(|&roll| if roll { '@' } else { '.' }), "...\n...\n...\n");
+    }
+}
@@ -0,0 +1,158 @@
+use aoc_core::gen::{Generator, Knob, Knobs};
+use aoc_core::parse::{self, ParseError};
+use aoc_core::rng::Rng;
+use aoc_core::{Answer, Solution};
+
+/// The fresh ingredient ID ranges and the IDs of the available ingredients.
+#[derive(Debug)]
+pub struct Inventory {
+    pub ranges: Vec<(u64, u64)>,
+    pub ingredient_ids: Vec<u64>,
+}
+
+pub fn parse_inventory(input: &str) -> Result<Inventory, ParseError> {
+    let parts = parse::sections(input);
+    match parts.len() {
+        0 => return Err(ParseError::at(input, &input[input.len()..], "a section of ID ranges")),
+        1 => {
+            let end = &input[input.len()..];
+            return Err(ParseError::at(input, end, "a blank line followed by ingredient IDs"));
+        }
+        2 => {}
+        _ => return Err(ParseError::at(input, parts[2], "only two sections")),
+    }
+    
+    // Parse fresh ingredient ID ranges
+    let ranges: Vec<(u64, u64)> = parse::lines(parts[0])
+        .map(|line| {
+            let Some((start, end)) = line.split_once('-') else {
+                return Err(ParseError::at(input, line, "a range like `3-5`"));
+            };
+            let start: u64 = parse::number(input, start, "a range start")?;
+            let end: u64 = parse::number(input, end, "a range end")?;
+            if start > end {
+                return Err(ParseError::at(input, line, "a range with start <= end"));
+            }
+            Ok((start, end))
+        })
+        .collect::<Result<_, _>>()?;
+    
+    // Parse available ingredient IDs
+    let ingredient_ids: Vec<u64> = parse::lines(parts[1])
+        .map(|line| parse::number(input, line, "an ingredient ID"))
+        .collect::<Result<_, _>>()?;
+    
+    Ok(Inventory { ranges, ingredient_ids })
+}
+
+pub fn is_fresh(id: u64, ranges: &[(u64, u64)]) -> bool {
+    ranges.iter().any(|&(start, end)| id >= start && id <= end)
+}
+
+pub fn merge_ranges(ranges: &mut [(u64, u64)]) -> Vec<(u64, u64)> {
+    if ranges.is_empty() {
+        return vec![];
+    }
+    
+    // Sort ranges by start value
+    ranges.sort_by_key(|&(start, _)| start);
+    
+    let mut merged: Vec<(u64, u64)> = vec![ranges[0]];
+    
+    for &(start, end) in ranges.iter().skip(1) {
+        let last = merged.last_mut().unwrap();
+        // Check if ranges overlap or are adjacent
+        if start <= last.1 + 1 {
+            // Merge by extending the end if necessary
+            last.1 = last.1.max(end);
+        } else {
+            // No overlap, add as new range
+            merged.push((start, end));
+        }
+    }
+    
+    merged
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    type Parsed = Inventory;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_inventory(input)
+    }
+
+    // Part 1: Count how many ingredient IDs are fresh (fall within any range)
+    fn part1(inventory: &Self::Parsed) -> Answer {
+        inventory
+            .ingredient_ids
+            .iter()
+            .filter(|&&id| is_fresh(id, &inventory.ranges))
+            .count()
+            .into()
+    }
+
+    // Part 2: Count total unique IDs considered fresh by the ranges
+    fn part2(inventory: &Self::Parsed) -> Answer {
+        let mut ranges = inventory.ranges.clone();
+        let merged_ranges = merge_ranges(&mut ranges);
+        let total_fresh_ids: u64 = merged_ranges
+            .iter()
+            .map(|&(start, end)| end - start + 1)
+            .sum();
+        total_fresh_ids.into()
+    }
+}
+
+impl Generator for Day5 {
+    const KNOBS: &'static [Knob] = &[
+        Knob { name: "ranges", default: 180, help: "number of fresh ID ranges" },
+        Knob { name: "ids", default: 1000, help: "number of available ingredient IDs" },
+        Knob { name: "max", default: 500_000_000_000_000, help: "largest ingredient ID" },
+        Knob { name: "span", default: 20_000_000_000_000, help: "widest fresh range" },
+    ];
+
+    fn generate(rng: &mut Rng, knobs: &Knobs) -> String {
+        let max = knobs.get("max").max(1);
+        let mut input = String::new();
+        for _ in 0..knobs.size("ranges").max(1) {
+            let start = rng.range(1..=max);
+            let end = start.saturating_add(rng.range(0..=knobs.get("span"))).min(max);
+            input.push_str(&format!("{}-{}\n", start, end));
+        }
+        input.push('\n');
+        for _ in 0..knobs.size("ids").max(1) {
+            input.push_str(&format!("{}\n", rng.range(1..=max)));
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_inventory() {
+        let inventory = parse_inventory("3-5\n10-14\n\n1\n5\n").unwrap();
+        assert_eq!(inventory.ranges, vec![(3, 5), (10, 14)]);
+        assert_eq!(inventory.ingredient_ids, vec![1, 5]);
+    }
+
+    #[test]
+    fn test_parse_inventory_errors() {
+        let err = parse_inventory("3-5\n10+14\n\n1\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.snippet.as_str()), (2, 1, "10+14"));
+
+        let err = parse_inventory("3-5\n\n1\nx7\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.snippet.as_str()), (4, 1, "x7"));
+
+        let err = parse_inventory("3-5\n").unwrap_err();
+        assert_eq!(err.expected, "a blank line followed by ingredient IDs");
+
+        let err = parse_inventory("5-3\n\n4\n").unwrap_err();
+        assert_eq!((err.line, err.snippet.as_str(), err.expected.as_str()), (1, "5-3", "a range with start <= end"));
+    }
+}
@@ -0,0 +1,213 @@
+use aoc_core::gen::{Generator, Knob, Knobs};
+use aoc_core::grid::Grid;
+use aoc_core::parse::ParseError;
+use aoc_core::rng::Rng;
+use aoc_core::{Answer, Solution};
+
+/// Parses the worksheet into a grid of characters, padding every line to the
+/// same width so columns line up.
+///
+/// Every line but the last holds digits, the last holds the operators.
+pub fn parse_worksheet(input: &str) -> Result<Grid<char>, ParseError> {
+    let lines: Vec<&str> = input
+        .trim_end_matches(['\n', '\r'])
+        .lines()
+        .map(|l| l.trim_end_matches('\r'))
+        .collect();
+    
+    for (i, line) in lines.iter().enumerate() {
+        let (allowed, expected) = if i + 1 == lines.len() {
+            (" *+", "an operator `*` or `+`")
+        } else {
+            (" 0123456789", "a digit")
+        };
+        if let Some((at, c)) = line.char_indices().find(|&(_, c)| !allowed.contains(c)) {
+            return Err(ParseError::at(input, &line[at..at + c.len_utf8()], expected));
+        }
+    }
+    
+    // Pad all lines to the same length so columns line up
+    Grid::parse_padded(input, ' ', Some, "a worksheet character")
+}
+
+pub fn solve(char_grid: &Grid<char>) -> u64 {
+    if char_grid.rows() == 0 {
+        return 0;
+    }
+    
+    let max_len = char_grid.cols();
+    
+    // The last line contains the operators
+    let num_rows = char_grid.rows();
+    let operator_row = num_rows - 1;
+    
+    // Find problem boundaries - columns that are all spaces (including operator row)
+    let is_separator: Vec<bool> = (0..max_len)
+        .map(|col| char_grid.column(col).all(|&c| c == ' '))
+        .collect();
+    
+    // Identify problem ranges (start_col, end_col exclusive)
+    let mut problems: Vec<(usize, usize)> = Vec::new();
+    let mut in_problem = false;
+    let mut start = 0;
+    
+    for col in 0..max_len {
+        if is_separator[col] {
+            if in_problem {
+                problems.push((start, col));
+                in_problem = false;
+            }
+        } else {
+            if !in_problem {
+                start = col;
+                in_problem = true;
+            }
+        }
+    }
+    if in_problem {
+        problems.push((start, max_len));
+    }
+    
+    // For each problem, parse the numbers
+    // Each COLUMN is ONE NUMBER, reading top-to-bottom as most-significant to least-significant digit
+    let mut grand_total: u64 = 0;
+    
+    for (start_col, end_col) in problems {
+        // Find the operator for this problem
+        let op = char_grid.row(operator_row)[start_col..end_col]
+            .iter()
+            .copied()
+            .find(|&c| c == '*' || c == '+')
+            .unwrap_or('+');
+        
+        // Parse numbers: each column is ONE number
+        // Read top-to-bottom as most-significant to least-significant digit
+        let mut numbers: Vec<u64> = Vec::new();
+        
+        for col in start_col..end_col {
+            // The digits of this column in the non-operator rows
+            let mut digits = char_grid
+                .column(col)
+                .take(operator_row)
+                .filter_map(|c| c.to_digit(10))
+                .peekable();
+            
+            if digits.peek().is_some() {
+                // Build the number from top to bottom
+                numbers.push(digits.fold(0, |num, d| num * 10 + d as u64));
+            }
+        }
+        
+        // Calculate result
+        let result = match op {
+            '*' => {
+                if numbers.is_empty() {
+                    0
+                } else {
+                    numbers.iter().product::<u64>()
+                }
+            },
+            '+' => numbers.iter().sum::<u64>(),
+            _ => 0,
+        };
+        
+        grand_total += result;
+    }
+    
+    grand_total
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    type Parsed = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_worksheet(input)
+    }
+
+    // Only the column-wise reading of part 2 is implemented.
+    fn part1(_: &Self::Parsed) -> Answer {
+        Answer::Unsolved
+    }
+
+    fn part2(char_grid: &Self::Parsed) -> Answer {
+        solve(char_grid).into()
+    }
+}
+
+impl Generator for Day6 {
+    const KNOBS: &'static [Knob] = &[
+        Knob { name: "problems", default: 1000, help: "number of problems side by side" },
+        Knob { name: "rows", default: 4, help: "number rows, so digits per column" },
+        Knob { name: "width", default: 4, help: "most columns per problem" },
+    ];
+
+    /// A problem's result has at most `rows * width` digits, so both knobs
+    /// are clamped to keep the grand total of every problem within a `u64`.
+    fn generate(rng: &mut Rng, knobs: &Knobs) -> String {
+        let problems = knobs.size("problems").max(1);
+        let digits = ((u64::MAX / problems as u64).ilog10() as usize).max(1);
+        let rows = knobs.size("rows").clamp(1, digits);
+        let most_width = knobs.size("width").clamp(1, digits / rows);
+        let mut lines = vec![String::new(); rows + 1];
+
+        for problem in 0..problems {
+            if problem > 0 {
+                lines.iter_mut().for_each(|line| line.push(' '));
+            }
+            let width = rng.range(1..=most_width as u64) as usize;
+            // One number spans the whole problem so no column inside it is blank.
+            let full = rng.index(rows);
+            for (row, line) in lines[..rows].iter_mut().enumerate() {
+                let len = if row == full { width } else { rng.range(1..=width as u64) as usize };
+                let offset = if rng.chance(50) { 0 } else { width - len };
+                let mut cells = vec![' '; width];
+                for cell in &mut cells[offset..offset + len] {
+                    *cell = char::from_digit(rng.range(1..=9) as u32, 10).unwrap();
+                }
+                line.extend(cells);
+            }
+            lines[rows].push(if rng.chance(50) { '*' } else { '+' });
+            lines[rows].extend(std::iter::repeat_n(' ', width - 1));
+        }
+
+        lines.join("\n") + "\n"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_part2() {
+        let input = "123 328  51 64 \n 45 64  387 23 \n  6 98  215 314\n*   +   *   +  ";
+        
+        // Each column is one number, reading top-to-bottom as MSB to LSB
+        // The expected answer is 3263827
+        assert_eq!(solve(&parse_worksheet(input).unwrap()), 3263827);
+    }
+
+    #[test]
+    fn test_generated_totals_fit_in_u64() {
+        let mut knobs = Knobs::new(Day6::KNOBS);
+        knobs.set("rows", 10);
+        knobs.set("width", 10);
+        for seed in 0..20 {
+            let input = Day6::generate(&mut Rng::new(seed), &knobs);
+            // Overflow would panic here in a debug build.
+            solve(&parse_worksheet(&input).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_parse_worksheet_errors() {
+        let err = parse_worksheet("12 3\n4- 5\n*  +").unwrap_err();
+        assert_eq!((err.line, err.column, err.snippet.as_str()), (2, 2, "-"));
+
+        let err = parse_worksheet("12 3\n*  7").unwrap_err();
+        assert_eq!((err.line, err.column, err.expected.as_str()), (2, 4, "an operator `*` or `+`"));
+    }
+}
@@ -0,0 +1,213 @@
+use aoc_core::gen::{Generator, Knob, Knobs};
+use aoc_core::grid::Grid;
+use aoc_core::parse::ParseError;
+use aoc_core::rng::Rng;
+use aoc_core::{Answer, Solution};
+use std::collections::HashMap;
+
+pub mod reference;
+
+/// The tachyon manifold diagram and the column the beam enters at.
+#[derive(Debug)]
+pub struct Manifold {
+    pub grid: Grid<char>,
+    pub start_col: usize,
+}
+
+pub fn parse_manifold(input: &str) -> Result<Manifold, ParseError> {
+    let grid = Grid::parse(input, |c| ".S^".contains(c).then_some(c), "one of `.S^`")?;
+
+    // Find the starting position 'S'
+    let Some((_, start_col)) = grid.find(&'S') else {
+        return Err(ParseError::at_offset(input, 0, 0, "a start position `S`"));
+    };
+
+    Ok(Manifold { grid, start_col })
+}
+
+pub fn solve_part1(manifold: &Manifold) -> usize {
+    let grid = &manifold.grid;
+    let (rows, cols) = (grid.rows(), grid.cols());
+    let start_col = manifold.start_col;
+
+    // Count unique splitters hit
+    let mut split_count = 0;
+    let mut visited_splitters: std::collections::HashSet<(usize, usize)> = std::collections::HashSet::new();
+    let mut beams: std::collections::VecDeque<(usize, usize)> = std::collections::VecDeque::new();
+    beams.push_back((1, start_col));
+    let mut processed_beams: std::collections::HashSet<(usize, usize)> = std::collections::HashSet::new();
+
+    while let Some((row, col)) = beams.pop_front() {
+        if row >= rows || col >= cols {
+            continue;
+        }
+        if processed_beams.contains(&(row, col)) {
+            continue;
+        }
+        processed_beams.insert((row, col));
+
+        let mut current_row = row;
+        while current_row < rows {
+            let ch = grid[(current_row, col)];
+            if ch == '^' {
+                if !visited_splitters.contains(&(current_row, col)) {
+                    visited_splitters.insert((current_row, col));
+                    split_count += 1;
+                    let next_row = current_row + 1;
+                    if col > 0 {
+                        beams.push_back((next_row, col - 1));
+                    }
+                    if col + 1 < cols {
+                        beams.push_back((next_row, col + 1));
+                    }
+                }
+                break;
+            }
+            current_row += 1;
+        }
+    }
+    split_count
+}
+
+pub fn solve_part2(manifold: &Manifold) -> u64 {
+    let grid = &manifold.grid;
+    let (rows, cols) = (grid.rows(), grid.cols());
+    let start_col = manifold.start_col;
+
+    // For part 2, we need to count timelines.
+    // Each timeline is a unique path through the manifold.
+    // When a particle hits a splitter, the timeline splits into 2.
+    // We track how many timelines are currently at each column position,
+    // and propagate them row by row.
+    
+    // Map from column -> number of timelines at that column
+    let mut timelines: HashMap<usize, u64> = HashMap::new();
+    timelines.insert(start_col, 1); // Start with 1 timeline at the starting position
+    
+    // Process row by row, starting from row 1 (below S)
+    for row in 1..rows {
+        if timelines.is_empty() {
+            break;
+        }
+        
+        // Check what's at each column where we have active timelines
+        let mut new_timelines: HashMap<usize, u64> = HashMap::new();
+        
+        for (&col, &count) in timelines.iter() {
+            if col >= cols {
+                continue;
+            }
+            
+            let ch = grid[(row, col)];
+            
+            if ch == '^' {
+                // Splitter: each timeline splits into two
+                // Left path
+                if col > 0 {
+                    *new_timelines.entry(col - 1).or_insert(0) += count;
+                }
+                // Right path  
+                if col + 1 < cols {
+                    *new_timelines.entry(col + 1).or_insert(0) += count;
+                }
+            } else {
+                // Empty space or other: timelines continue straight down
+                *new_timelines.entry(col).or_insert(0) += count;
+            }
+        }
+        
+        timelines = new_timelines;
+    }
+    
+    // Sum up all remaining timelines
+    timelines.values().sum()
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    type Parsed = Manifold;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_manifold(input)
+    }
+
+    fn part1(manifold: &Self::Parsed) -> Answer {
+        solve_part1(manifold).into()
+    }
+
+    fn part2(manifold: &Self::Parsed) -> Answer {
+        solve_part2(manifold).into()
+    }
+}
+
+impl Generator for Day7 {
+    const KNOBS: &'static [Knob] = &[
+        Knob { name: "rows", default: 142, help: "rows of the manifold diagram" },
+        Knob { name: "cols", default: 141, help: "columns of the manifold diagram" },
+        Knob { name: "density", default: 30, help: "percentage of splitter-row cells holding a splitter" },
+    ];
+
+    fn generate(rng: &mut Rng, knobs: &Knobs) -> String {
+        let (rows, cols) = (knobs.size("rows").max(2), knobs.size("cols").max(1));
+        let mut manifold = Grid::new(rows, cols, '.');
+        manifold[(0, rng.index(cols))] = 'S';
+        // Like the real diagrams, splitters only sit on every other row.
+        for row in (2..rows).step_by(2) {
+            for col in 0..cols {
+                if rng.chance(knobs.get("density")) {
+                    manifold[(row, col)] = '^';
+                }
+            }
+        }
+        manifold.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = ".......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...............
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+...............";
+
+    #[test]
+    fn test_part1_example() {
+        assert_eq!(solve_part1(&parse_manifold(EXAMPLE).unwrap()), 21);
+    }
+
+    #[test]
+    fn test_part2_example() {
+        assert_eq!(solve_part2(&parse_manifold(EXAMPLE).unwrap()), 40);
+    }
+
+    #[test]
+    fn test_solution_example() {
+        let (part1, part2) = aoc_core::solution::solve::<Day7>(EXAMPLE).unwrap();
+        assert_eq!(part1, Answer::Unsigned(21));
+        assert_eq!(part2, Answer::Unsigned(40));
+    }
+
+    #[test]
+    fn test_parse_manifold_errors() {
+        let err = parse_manifold("..S..\n..#..\n").unwrap_err();
+        assert_eq!(err.expected, "one of `.S^`");
+        let err = parse_manifold("...\n.^.\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.expected.as_str()), (1, 1, "a start position `S`"));
+    }
+}
@@ -0,0 +1,114 @@
+//! A slow but obviously correct reference for both parts, used to check the
+//! beam search and the timeline DP on small random manifolds.
+
+use crate::{solve_part1, solve_part2, Manifold};
+use aoc_core::grid::Grid;
+use aoc_core::oracle::{Check, Oracle};
+use std::collections::HashSet;
+
+/// Follows every timeline one at a time, returning how many splitters any
+/// of them hits and how many timelines reach the bottom.
+pub fn splits_and_timelines(manifold: &Manifold) -> (usize, u64) {
+    fn follow(grid: &Grid<char>, row: usize, col: usize, hit: &mut HashSet<(usize, usize)>) -> u64 {
+        for row in row..grid.rows() {
+            if grid[(row, col)] == '^' {
+                hit.insert((row, col));
+                // A timeline pushed off either side of the manifold is lost.
+                let mut timelines = 0;
+                if col > 0 {
+                    timelines += follow(grid, row + 1, col - 1, hit);
+                }
+                if col + 1 < grid.cols() {
+                    timelines += follow(grid, row + 1, col + 1, hit);
+                }
+                return timelines;
+            }
+        }
+        1
+    }
+
+    let mut hit = HashSet::new();
+    let timelines = follow(&manifold.grid, 1, manifold.start_col, &mut hit);
+    (hit.len(), timelines)
+}
+
+/// The manifold with only the given rows and columns, in order.
+fn keep(manifold: &Manifold, rows: &[usize], cols: &[usize]) -> Manifold {
+    let mut grid = Grid::new(rows.len(), cols.len(), '.');
+    for (r, &row) in rows.iter().enumerate() {
+        for (c, &col) in cols.iter().enumerate() {
+            grid[(r, c)] = manifold.grid[(row, col)];
+        }
+    }
+    let start_col = cols.iter().position(|&col| col == manifold.start_col).unwrap();
+    Manifold { grid, start_col }
+}
+
+fn shrink(manifold: &Manifold) -> Vec<Manifold> {
+    let rows: Vec<usize> = (0..manifold.grid.rows()).collect();
+    let cols: Vec<usize> = (0..manifold.grid.cols()).collect();
+    let without = |items: &[usize], skip: usize| -> Vec<usize> {
+        items.iter().copied().filter(|&i| i != skip).collect()
+    };
+
+    let mut candidates = Vec::new();
+    if rows.len() > 2 {
+        candidates.extend((1..rows.len()).map(|row| keep(manifold, &without(&rows, row), &cols)));
+    }
+    candidates.extend(
+        cols.iter()
+            .filter(|&&col| col != manifold.start_col)
+            .map(|&col| keep(manifold, &rows, &without(&cols, col))),
+    );
+    for (pos, _) in manifold.grid.iter().filter(|&(_, &c)| c == '^') {
+        let mut cleared = keep(manifold, &rows, &cols);
+        cleared.grid[pos] = '.';
+        candidates.push(cleared);
+    }
+    candidates
+}
+
+pub fn oracles() -> Vec<Box<dyn Check>> {
+    vec![Box::new(Oracle {
+        name: "splits and timelines",
+        generate: |rng| {
+            let (rows, cols) = (rng.range(2..=10) as usize, rng.range(1..=9) as usize);
+            let start_col = rng.index(cols);
+            let mut grid = Grid::new(rows, cols, '.');
+            grid[(0, start_col)] = 'S';
+            for row in 1..rows {
+                for col in 0..cols {
+                    if rng.chance(35) {
+                        grid[(row, col)] = '^';
+                    }
+                }
+            }
+            Manifold { grid, start_col }
+        },
+        shrink,
+        render: |manifold| manifold.grid.to_string(),
+        fast: |manifold| (solve_part1(manifold), solve_part2(manifold)),
+        reference: splits_and_timelines,
+    })]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_manifold;
+
+    #[test]
+    fn test_splits_and_timelines() {
+        let manifold = parse_manifold("..S..\n.....\n..^..\n.^...\n").unwrap();
+        assert_eq!(splits_and_timelines(&manifold), (2, 3));
+    }
+
+    #[test]
+    fn test_fast_matches_reference() {
+        for check in oracles() {
+            if let Err(mismatch) = check.run(0, 300) {
+                panic!("{}: {}", check.name(), mismatch);
+            }
+        }
+    }
+}
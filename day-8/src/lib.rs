@@ -0,0 +1,245 @@
+use aoc_core::gen::{Generator, Knob, Knobs};
+use aoc_core::parse::{self, ParseError};
+use aoc_core::rng::Rng;
+use aoc_core::{Answer, Detail, Part, Solution};
+
+/// Represents a 3D point (junction box position)
+#[derive(Debug, Clone, Copy)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point {
+    /// Parses an `x,y,z` line, a slice of `source`.
+    pub fn from_line(source: &str, line: &str) -> Result<Point, ParseError> {
+        let coords: Vec<i64> = parse::numbers(source, line, ',')?;
+        if coords.len() != 3 {
+            return Err(ParseError::at(source, line, "three coordinates like `162,817,812`"));
+        }
+        Ok(Point {
+            x: coords[0],
+            y: coords[1],
+            z: coords[2],
+        })
+    }
+
+    /// Calculate squared Euclidean distance to avoid floating point
+    pub fn distance_squared(&self, other: &Point) -> i64 {
+        let dx = self.x - other.x;
+        let dy = self.y - other.y;
+        let dz = self.z - other.z;
+        dx * dx + dy * dy + dz * dz
+    }
+}
+
+/// Union-Find (Disjoint Set Union) data structure for tracking circuits
+pub struct UnionFind {
+    parent: Vec<usize>,
+    rank: Vec<usize>,
+    size: Vec<usize>,
+}
+
+impl UnionFind {
+    pub fn new(n: usize) -> Self {
+        UnionFind {
+            parent: (0..n).collect(),
+            rank: vec![0; n],
+            size: vec![1; n],
+        }
+    }
+
+    pub fn find(&mut self, x: usize) -> usize {
+        if self.parent[x] != x {
+            self.parent[x] = self.find(self.parent[x]); // Path compression
+        }
+        self.parent[x]
+    }
+
+    /// Union two sets. Returns true if they were in different sets.
+    pub fn union(&mut self, x: usize, y: usize) -> bool {
+        let root_x = self.find(x);
+        let root_y = self.find(y);
+
+        if root_x == root_y {
+            return false; // Already in the same circuit
+        }
+
+        // Union by rank
+        if self.rank[root_x] < self.rank[root_y] {
+            self.parent[root_x] = root_y;
+            self.size[root_y] += self.size[root_x];
+        } else if self.rank[root_x] > self.rank[root_y] {
+            self.parent[root_y] = root_x;
+            self.size[root_x] += self.size[root_y];
+        } else {
+            self.parent[root_y] = root_x;
+            self.size[root_x] += self.size[root_y];
+            self.rank[root_x] += 1;
+        }
+        true
+    }
+
+    /// Get sizes of all unique circuits
+    pub fn get_circuit_sizes(&mut self) -> Vec<usize> {
+        let n = self.parent.len();
+        let mut sizes = Vec::new();
+        for i in 0..n {
+            if self.find(i) == i {
+                sizes.push(self.size[i]);
+            }
+        }
+        sizes
+    }
+}
+
+/// Represents a pair of junction boxes with their distance
+#[derive(Debug)]
+pub struct Pair {
+    pub i: usize,
+    pub j: usize,
+    pub distance_sq: i64,
+}
+
+/// Number of closest pairs connected in part 1.
+pub const CONNECTIONS_TO_MAKE: usize = 1000;
+
+/// The junction boxes together with every pair of them, closest first.
+pub struct Playground {
+    pub points: Vec<Point>,
+    pub pairs: Vec<Pair>,
+}
+
+pub fn parse_playground(input: &str) -> Result<Playground, ParseError> {
+    // Parse all junction box positions
+    let points: Vec<Point> = parse::lines(input)
+        .map(|line| Point::from_line(input, line))
+        .collect::<Result<_, _>>()?;
+
+    let n = points.len();
+
+    // Calculate all pairwise distances
+    let mut pairs: Vec<Pair> = Vec::with_capacity(n * n.saturating_sub(1) / 2);
+    for i in 0..n {
+        for j in (i + 1)..n {
+            pairs.push(Pair {
+                i,
+                j,
+                distance_sq: points[i].distance_squared(&points[j]),
+            });
+        }
+    }
+
+    // Sort pairs by distance (ascending)
+    pairs.sort_by_key(|p| p.distance_sq);
+
+    Ok(Playground { points, pairs })
+}
+
+/// Connects the `connections` closest pairs and returns the resulting
+/// circuit sizes, largest first.
+pub fn circuit_sizes(playground: &Playground, connections: usize) -> Vec<usize> {
+    let mut uf = UnionFind::new(playground.points.len());
+    
+    for pair in playground.pairs.iter().take(connections) {
+        uf.union(pair.i, pair.j);
+    }
+
+    let mut circuit_sizes = uf.get_circuit_sizes();
+    circuit_sizes.sort_by(|a, b| b.cmp(a));
+    circuit_sizes
+}
+
+/// Continues connecting until all junction boxes are in one circuit and
+/// returns the pair whose connection completed it.
+pub fn last_connection(playground: &Playground) -> Option<&Pair> {
+    let n = playground.points.len();
+    let mut uf = UnionFind::new(n);
+    let mut num_circuits = n; // Start with n individual circuits
+
+    for pair in playground.pairs.iter() {
+        // Only count actual merges (when two different circuits are connected)
+        if uf.union(pair.i, pair.j) {
+            num_circuits -= 1;
+            
+            if num_circuits == 1 {
+                return Some(pair);
+            }
+        }
+    }
+
+    None
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    type Parsed = Playground;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_playground(input)
+    }
+
+    // Part 1: Connect the 1000 closest pairs
+    fn part1(playground: &Self::Parsed) -> Answer {
+        let sizes = circuit_sizes(playground, CONNECTIONS_TO_MAKE);
+        sizes.iter().take(3).product::<usize>().into()
+    }
+
+    // Part 2: Continue connecting until all junction boxes are in one circuit
+    fn part2(playground: &Self::Parsed) -> Answer {
+        match last_connection(playground) {
+            Some(pair) => {
+                let p1 = &playground.points[pair.i];
+                let p2 = &playground.points[pair.j];
+                (p1.x * p2.x).into()
+            }
+            None => Answer::Unsolved,
+        }
+    }
+
+    fn details(playground: &Self::Parsed, part: Part) -> Vec<(&'static str, Detail)> {
+        match part {
+            Part::One => {
+                let sizes = circuit_sizes(playground, CONNECTIONS_TO_MAKE);
+                let top: Vec<i64> = sizes.iter().take(10).map(|&s| s as i64).collect();
+                vec![
+                    ("junction_boxes", Detail::Int(playground.points.len() as i64)),
+                    ("circuits", Detail::Int(sizes.len() as i64)),
+                    ("top_circuit_sizes", Detail::List(top)),
+                ]
+            }
+            Part::Two => match last_connection(playground) {
+                Some(pair) => {
+                    let p1 = &playground.points[pair.i];
+                    let p2 = &playground.points[pair.j];
+                    vec![
+                        ("last_connection_a", Detail::List(vec![p1.x, p1.y, p1.z])),
+                        ("last_connection_b", Detail::List(vec![p2.x, p2.y, p2.z])),
+                    ]
+                }
+                None => Vec::new(),
+            },
+        }
+    }
+}
+
+impl Generator for Day8 {
+    const KNOBS: &'static [Knob] = &[
+        Knob { name: "points", default: 1000, help: "number of junction boxes" },
+        Knob { name: "max", default: 100_000, help: "largest coordinate" },
+    ];
+
+    fn generate(rng: &mut Rng, knobs: &Knobs) -> String {
+        // Squared distances must fit in an i64.
+        let max = knobs.get("max").min(1_000_000_000);
+        let mut input = String::new();
+        for _ in 0..knobs.size("points").max(1) {
+            let [x, y, z] = [(); 3].map(|_| rng.range(0..=max));
+            input.push_str(&format!("{},{},{}\n", x, y, z));
+        }
+        input
+    }
+}
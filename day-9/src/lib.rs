@@ -0,0 +1,413 @@
+use aoc_core::gen::{Generator, Knob, Knobs};
+use aoc_core::grid::{Grid, Pos, NEIGHBOURS_4};
+use aoc_core::parse::{self, ParseError};
+use aoc_core::rng::Rng;
+use aoc_core::{Answer, Solution};
+use std::collections::HashMap;
+
+pub mod reference;
+
+pub fn parse_red_points(input: &str) -> Result<Vec<(i64, i64)>, ParseError> {
+    parse::lines(input)
+        .map(|line| {
+            let parts: Vec<i64> = parse::numbers(input, line, ',')?;
+            match parts[..] {
+                [x, y] => Ok((x, y)),
+                _ => Err(ParseError::at(input, line, "a tile position like `7,1`")),
+            }
+        })
+        .collect()
+}
+
+/// Part 1: Find largest rectangle using any two red tiles as opposite corners
+pub fn largest_rectangle(red_points: &[(i64, i64)]) -> i64 {
+    let mut max_area_part1: i64 = 0;
+    for i in 0..red_points.len() {
+        for j in (i + 1)..red_points.len() {
+            let (x1, y1) = red_points[i];
+            let (x2, y2) = red_points[j];
+            if x1 != x2 && y1 != y2 {
+                let width = (x2 - x1).abs() + 1;
+                let height = (y2 - y1).abs() + 1;
+                let area = width * height;
+                if area > max_area_part1 {
+                    max_area_part1 = area;
+                }
+            }
+        }
+    }
+    max_area_part1
+}
+
+/// The loop of red tiles, stored as its horizontal and vertical boundary
+/// segments for efficient checking.
+pub struct Polygon {
+    h_segments: Vec<(i64, i64, i64)>, // (y, x_min, x_max)
+    v_segments: Vec<(i64, i64, i64)>, // (x, y_min, y_max)
+}
+
+impl Polygon {
+    pub fn new(red_points: &[(i64, i64)]) -> Self {
+        let mut h_segments: Vec<(i64, i64, i64)> = Vec::new();
+        let mut v_segments: Vec<(i64, i64, i64)> = Vec::new();
+
+        for i in 0..red_points.len() {
+            let (x1, y1) = red_points[i];
+            let (x2, y2) = red_points[(i + 1) % red_points.len()];
+
+            if x1 == x2 {
+                // Vertical segment
+                let (min_y, max_y) = if y1 < y2 { (y1, y2) } else { (y2, y1) };
+                v_segments.push((x1, min_y, max_y));
+            } else if y1 == y2 {
+                // Horizontal segment
+                let (min_x, max_x) = if x1 < x2 { (x1, x2) } else { (x2, x1) };
+                h_segments.push((y1, min_x, max_x));
+            }
+        }
+
+        Polygon { h_segments, v_segments }
+    }
+
+    fn is_on_boundary(&self, x: i64, y: i64) -> bool {
+        // Check horizontal segments
+        for &(seg_y, x_min, x_max) in &self.h_segments {
+            if y == seg_y && x >= x_min && x <= x_max {
+                return true;
+            }
+        }
+        // Check vertical segments
+        for &(seg_x, y_min, y_max) in &self.v_segments {
+            if x == seg_x && y >= y_min && y <= y_max {
+                return true;
+            }
+        }
+        false
+    }
+
+    // For a point to be inside or on boundary of the polygon, use ray casting
+    // Count how many vertical segments are to the right of the point
+    // If odd, point is inside
+    fn is_inside(&self, x: i64, y: i64) -> bool {
+        // Ray casting: count crossings to the right
+        let mut crossings = 0;
+        for &(seg_x, y_min, y_max) in &self.v_segments {
+            if seg_x > x && y > y_min && y <= y_max {
+                crossings += 1;
+            }
+        }
+        crossings % 2 == 1
+    }
+
+    pub fn is_red_or_green(&self, x: i64, y: i64) -> bool {
+        self.is_on_boundary(x, y) || self.is_inside(x, y)
+    }
+
+    // For a rectangle to be valid, all four corners and all edges must be inside
+    // But actually we need ALL tiles inside to be red or green
+    // For efficiency: check if the rectangle's boundary is fully within the polygon
+    // and then use the property that if boundary is inside a simple polygon, interior is too
+
+    // Actually, for a rectilinear polygon, we can check more efficiently:
+    // The rectangle is valid if all its corners are inside/on boundary AND
+    // no polygon edge crosses through the interior of the rectangle
+    pub fn rect_valid(&self, left: i64, right: i64, top: i64, bottom: i64) -> bool {
+        // Check all four corners
+        let corners = [(left, top), (right, top), (left, bottom), (right, bottom)];
+        for &(cx, cy) in &corners {
+            if !self.is_red_or_green(cx, cy) {
+                return false;
+            }
+        }
+
+        // Check if any vertical segment of the polygon crosses the rectangle interior
+        // A vertical segment at x=seg_x from y_min to y_max crosses if:
+        // - seg_x is strictly between left and right
+        // - the segment overlaps with [top, bottom] range
+        for &(seg_x, y_min, y_max) in &self.v_segments {
+            if seg_x > left && seg_x < right {
+                // Check if this segment crosses through the rectangle
+                if y_min < bottom && y_max > top {
+                    return false;
+                }
+            }
+        }
+
+        // Check if any horizontal segment of the polygon crosses the rectangle interior
+        for &(seg_y, x_min, x_max) in &self.h_segments {
+            if seg_y > top && seg_y < bottom {
+                // Check if this segment crosses through the rectangle
+                if x_min < right && x_max > left {
+                    return false;
+                }
+            }
+        }
+
+        // With no edge inside it, the rectangle's interior is either wholly
+        // inside the polygon or wholly outside, as it is when the corners sit
+        // on the rim of a notch. Test a point just inside the top-left corner.
+        self.is_inside_half(2 * left + 1, 2 * top + 1)
+    }
+
+    // Ray casting for the point (x2 / 2, y2 / 2), given in doubled
+    // coordinates so that it can sit between tiles and never on an edge
+    fn is_inside_half(&self, x2: i64, y2: i64) -> bool {
+        let crossings = self
+            .v_segments
+            .iter()
+            .filter(|&&(seg_x, y_min, y_max)| 2 * seg_x > x2 && 2 * y_min < y2 && y2 < 2 * y_max)
+            .count();
+        crossings % 2 == 1
+    }
+}
+
+/// Part 2: Find largest rectangle where corners are red and entire rectangle is red/green
+pub fn largest_enclosed_rectangle(red_points: &[(i64, i64)]) -> i64 {
+    let polygon = Polygon::new(red_points);
+    let mut max_area_part2: i64 = 0;
+
+    for i in 0..red_points.len() {
+        for j in (i + 1)..red_points.len() {
+            let (x1, y1) = red_points[i];
+            let (x2, y2) = red_points[j];
+
+            if x1 == x2 || y1 == y2 {
+                continue;
+            }
+
+            let left = x1.min(x2);
+            let right = x1.max(x2);
+            let top = y1.min(y2);
+            let bottom = y1.max(y2);
+
+            let width = right - left + 1;
+            let height = bottom - top + 1;
+            let area = width * height;
+
+            // Skip if can't beat current best
+            if area <= max_area_part2 {
+                continue;
+            }
+
+            if polygon.rect_valid(left, right, top, bottom) {
+                max_area_part2 = area;
+            }
+        }
+    }
+
+    max_area_part2
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    type Parsed = Vec<(i64, i64)>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_red_points(input)
+    }
+
+    fn part1(red_points: &Self::Parsed) -> Answer {
+        largest_rectangle(red_points).into()
+    }
+
+    fn part2(red_points: &Self::Parsed) -> Answer {
+        largest_enclosed_rectangle(red_points).into()
+    }
+}
+
+impl Generator for Day9 {
+    const KNOBS: &'static [Knob] = &[
+        Knob { name: "cells", default: 120, help: "lattice cells the polygon encloses" },
+        Knob { name: "lattice", default: 24, help: "side of the lattice the polygon is drawn on" },
+        Knob { name: "spread", default: 4000, help: "largest gap between neighbouring lattice lines" },
+    ];
+
+    /// Grows a random polyomino without holes or pinch points on a coarse
+    /// lattice, traces its outline and spreads the lattice lines out to random
+    /// coordinates, giving a simple rectilinear polygon.
+    fn generate(rng: &mut Rng, knobs: &Knobs) -> String {
+        let side = knobs.size("lattice").max(2);
+        let shape = random_polyomino(rng, side, knobs.size("cells").clamp(1, side * side));
+        let spread = knobs.get("spread").max(2);
+        // Lines at least two apart keep parallel edges from touching.
+        let lines = |rng: &mut Rng| -> Vec<u64> {
+            let mut at = rng.range(0..=spread);
+            (0..=side)
+                .map(|_| {
+                    let line = at;
+                    at += rng.range(2..=spread);
+                    line
+                })
+                .collect()
+        };
+        let (xs, ys) = (lines(rng), lines(rng));
+
+        trace_outline(&shape)
+            .into_iter()
+            .map(|(row, col)| format!("{},{}\n", xs[col], ys[row]))
+            .collect()
+    }
+}
+
+fn random_polyomino(rng: &mut Rng, side: usize, cells: usize) -> Grid<bool> {
+    let mut shape = Grid::new(side, side, false);
+    let mut filled = vec![(side / 2, side / 2)];
+    shape[filled[0]] = true;
+    while filled.len() < cells {
+        let from = *rng.choose(&filled);
+        if let Some(next) = shape.step(from, *rng.choose(&NEIGHBOURS_4)) {
+            if !shape[next] {
+                shape[next] = true;
+                filled.push(next);
+            }
+        }
+    }
+
+    // Filling holes can create pinch points and filling those can enclose
+    // new holes, so repeat until neither is left.
+    while fill_holes(&mut shape) | fill_pinches(&mut shape) {}
+    shape
+}
+
+/// Fills every empty cell that the outside cannot reach. Returns whether any
+/// cell changed.
+fn fill_holes(shape: &mut Grid<bool>) -> bool {
+    let mut outside = Grid::new(shape.rows(), shape.cols(), false);
+    let mut stack: Vec<Pos> = shape
+        .positions()
+        .filter(|&(r, c)| r == 0 || c == 0 || r + 1 == shape.rows() || c + 1 == shape.cols())
+        .filter(|&pos| !shape[pos])
+        .collect();
+    while let Some(pos) = stack.pop() {
+        if !outside[pos] {
+            outside[pos] = true;
+            stack.extend(shape.neighbours4(pos).filter(|&n| !shape[n] && !outside[n]));
+        }
+    }
+
+    let holes: Vec<Pos> = shape.positions().filter(|&p| !shape[p] && !outside[p]).collect();
+    for &pos in &holes {
+        shape[pos] = true;
+    }
+    !holes.is_empty()
+}
+
+/// Fills one cell of every 2x2 block whose filled cells only touch at a
+/// corner, where the outline would cross itself. Returns whether any cell
+/// changed.
+fn fill_pinches(shape: &mut Grid<bool>) -> bool {
+    let mut changed = false;
+    for row in 0..shape.rows() - 1 {
+        for col in 0..shape.cols() - 1 {
+            let [a, b, c, d] = [(row, col), (row, col + 1), (row + 1, col), (row + 1, col + 1)];
+            if shape[a] == shape[d] && shape[b] == shape[c] && shape[a] != shape[b] {
+                let fill = if shape[a] { b } else { a };
+                shape[fill] = true;
+                changed = true;
+            }
+        }
+    }
+    changed
+}
+
+/// Walks the outline of a polyomino without holes or pinch points clockwise,
+/// returning the lattice points where it turns.
+fn trace_outline(shape: &Grid<bool>) -> Vec<Pos> {
+    // Each boundary side of a filled cell, directed so the cell is on the right.
+    let mut next: HashMap<Pos, Pos> = HashMap::new();
+    for (row, col) in shape.positions().filter(|&pos| shape[pos]) {
+        let empty = |dr: isize, dc: isize| shape.step((row, col), (dr, dc)).is_none_or(|n| !shape[n]);
+        if empty(-1, 0) {
+            next.insert((row, col), (row, col + 1));
+        }
+        if empty(0, 1) {
+            next.insert((row, col + 1), (row + 1, col + 1));
+        }
+        if empty(1, 0) {
+            next.insert((row + 1, col + 1), (row + 1, col));
+        }
+        if empty(0, -1) {
+            next.insert((row + 1, col), (row, col));
+        }
+    }
+
+    let start = *next.keys().min().expect("polyomino has at least one cell");
+    let direction = |from: Pos, to: Pos| (to.0 as isize - from.0 as isize, to.1 as isize - from.1 as isize);
+    let mut corners = Vec::new();
+    let mut from = *next.iter().find(|&(_, &to)| to == start).unwrap().0;
+    let mut at = start;
+    loop {
+        let to = next[&at];
+        if direction(from, at) != direction(at, to) {
+            corners.push(at);
+        }
+        (from, at) = (at, to);
+        if at == start {
+            break;
+        }
+    }
+    corners
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../test_input.txt");
+
+    #[test]
+    fn test_part1_example() {
+        assert_eq!(largest_rectangle(&parse_red_points(EXAMPLE).unwrap()), 50);
+    }
+
+    #[test]
+    fn test_part2_example() {
+        assert_eq!(largest_enclosed_rectangle(&parse_red_points(EXAMPLE).unwrap()), 24);
+    }
+
+    #[test]
+    fn test_part2_ignores_rectangles_filling_a_notch() {
+        // The corners of the notch between x = 4 and x = 7 are all red, but
+        // none of its inside is.
+        let red_points = parse_red_points("0,0\n7,0\n7,2\n4,2\n4,8\n7,8\n7,10\n2,10\n2,5\n0,5\n").unwrap();
+        assert_eq!(largest_enclosed_rectangle(&red_points), 27);
+    }
+
+    #[test]
+    fn test_parse_red_points_errors() {
+        let err = parse_red_points("7,1\n11\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.snippet.as_str()), (2, 1, "11"));
+        let err = parse_red_points("7,1\n11,-\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.snippet.as_str()), (2, 4, "-"));
+    }
+
+    #[test]
+    fn test_generated_polygons_are_simple_and_rectilinear() {
+        let knobs = Knobs::new(Day9::KNOBS);
+        for seed in 0..50 {
+            let points = parse_red_points(&Day9::generate(&mut Rng::new(seed), &knobs)).unwrap();
+            let n = points.len();
+            assert!(n >= 4 && n.is_multiple_of(2));
+            let edges: Vec<_> = (0..n).map(|i| (points[i], points[(i + 1) % n])).collect();
+            for (i, &((x1, y1), (x2, y2))) in edges.iter().enumerate() {
+                // Axis aligned, and turning at every corner.
+                assert!((x1 == x2) != (y1 == y2), "seed {}: bad edge {}", seed, i);
+                let ((x3, y3), _) = edges[(i + 1) % n];
+                assert_eq!((x2, y2), (x3, y3));
+                // No edge touches another one it is not joined to.
+                for &((u1, v1), (u2, v2)) in edges.iter().skip(i + 2) {
+                    if i == 0 && (u2, v2) == points[0] {
+                        continue;
+                    }
+                    let apart = x1.max(x2) < u1.min(u2)
+                        || u1.max(u2) < x1.min(x2)
+                        || y1.max(y2) < v1.min(v2)
+                        || v1.max(v2) < y1.min(y2);
+                    assert!(apart, "seed {}: edge {} touches a later edge", seed, i);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,116 @@
+//! A slow but obviously correct reference for part 2, used to check the
+//! "corners inside and no edge crossing" rule on small random polygons.
+
+use crate::{largest_enclosed_rectangle, parse_red_points, Day9};
+use aoc_core::gen::{Generator, Knobs};
+use aoc_core::grid::Grid;
+use aoc_core::oracle::{Check, Oracle};
+
+/// The largest rectangle with red corners whose every tile is red or green,
+/// found by drawing the loop tile by tile, flooding the outside and testing
+/// every tile of every candidate rectangle.
+pub fn largest_enclosed_rectangle_by_tiles(red_points: &[(i64, i64)]) -> i64 {
+    if red_points.is_empty() {
+        return 0;
+    }
+    // One spare tile around the loop lets the flood reach all of the outside.
+    let left = red_points.iter().map(|p| p.0).min().unwrap() - 1;
+    let top = red_points.iter().map(|p| p.1).min().unwrap() - 1;
+    let width = (red_points.iter().map(|p| p.0).max().unwrap() - left + 2) as usize;
+    let height = (red_points.iter().map(|p| p.1).max().unwrap() - top + 2) as usize;
+    let at = |(x, y): (i64, i64)| ((y - top) as usize, (x - left) as usize);
+
+    let mut on_loop = Grid::new(height, width, false);
+    for (i, &(x1, y1)) in red_points.iter().enumerate() {
+        let (x2, y2) = red_points[(i + 1) % red_points.len()];
+        for x in x1.min(x2)..=x1.max(x2) {
+            for y in y1.min(y2)..=y1.max(y2) {
+                on_loop[at((x, y))] = true;
+            }
+        }
+    }
+    let mut outside = Grid::new(height, width, false);
+    let mut stack = vec![(0, 0)];
+    while let Some(pos) = stack.pop() {
+        if !outside[pos] && !on_loop[pos] {
+            outside[pos] = true;
+            stack.extend(outside.neighbours4(pos));
+        }
+    }
+
+    let mut best = 0;
+    for &(x1, y1) in red_points {
+        for &(x2, y2) in red_points {
+            let enclosed = (x1.min(x2)..=x1.max(x2))
+                .all(|x| (y1.min(y2)..=y1.max(y2)).all(|y| !outside[at((x, y))]));
+            if enclosed {
+                best = best.max(((x1 - x2).abs() + 1) * ((y1 - y2).abs() + 1));
+            }
+        }
+    }
+    best
+}
+
+/// Moves every coordinate past each gap wider than two one step closer.
+/// Generated loops keep parallel edges at least two apart, and so do these.
+fn shrink(red_points: &[(i64, i64)]) -> Vec<Vec<(i64, i64)>> {
+    let mut candidates = Vec::new();
+    for axis in 0..2 {
+        let coord = |p: &(i64, i64)| if axis == 0 { p.0 } else { p.1 };
+        let mut lines: Vec<i64> = red_points.iter().map(coord).collect();
+        lines.sort_unstable();
+        lines.dedup();
+        let mut cuts: Vec<i64> = lines.windows(2).filter(|w| w[1] - w[0] > 2).map(|w| w[1]).collect();
+        if lines.first().is_some_and(|&first| first > 0) {
+            cuts.insert(0, lines[0]);
+        }
+        for cut in cuts {
+            let closer = |v: i64| if v >= cut { v - 1 } else { v };
+            candidates.push(
+                red_points
+                    .iter()
+                    .map(|&(x, y)| if axis == 0 { (closer(x), y) } else { (x, closer(y)) })
+                    .collect(),
+            );
+        }
+    }
+    candidates
+}
+
+pub fn oracles() -> Vec<Box<dyn Check>> {
+    vec![Box::new(Oracle {
+        name: "largest enclosed rectangle",
+        generate: |rng| {
+            let mut knobs = Knobs::new(Day9::KNOBS);
+            let lattice = rng.range(2..=5);
+            knobs.set("lattice", lattice);
+            knobs.set("cells", rng.range(1..=lattice * lattice));
+            knobs.set("spread", rng.range(2..=4));
+            parse_red_points(&Day9::generate(rng, &knobs)).unwrap()
+        },
+        shrink: |red_points| shrink(red_points),
+        render: |red_points| red_points.iter().map(|(x, y)| format!("{},{}\n", x, y)).collect(),
+        fast: |red_points| largest_enclosed_rectangle(red_points),
+        reference: |red_points| largest_enclosed_rectangle_by_tiles(red_points),
+    })]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let red_points = parse_red_points(include_str!("../test_input.txt")).unwrap();
+        assert_eq!(largest_enclosed_rectangle_by_tiles(&red_points), 24);
+    }
+
+    #[test]
+    fn test_fast_matches_reference() {
+        for check in oracles() {
+            if let Err(mismatch) = check.run(0, 300) {
+                panic!("{}: {}", check.name(), mismatch);
+            }
+        }
+    }
+}